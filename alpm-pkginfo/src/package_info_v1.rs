@@ -199,6 +199,18 @@ macro_rules! generate_pkginfo {
             pub fn checkdepend(&self) -> &[PackageRelation] {
                 &self.checkdepend
             }
+
+            /// Returns a hash over the canonical representation of the package information
+            ///
+            /// The lines of the string representation are sorted before calculating a SHA-256
+            /// checksum over them. This way, two instances that only differ in the order of
+            /// entries (e.g. of dependencies) produce the same hash.
+            pub fn content_hash(&self) -> String {
+                let representation = self.to_string();
+                let mut lines = representation.lines().collect::<Vec<_>>();
+                lines.sort_unstable();
+                alpm_types::Sha256Checksum::calculate_from(lines.join("\n")).to_string()
+            }
         }
     }
 }
@@ -504,4 +516,31 @@ checkdepend = other-extra-test-tool"#
         valid_pkginfov1.push_str(duplicate);
         assert!(PackageInfoV1::from_str(&valid_pkginfov1).is_err());
     }
+
+    #[rstest]
+    fn pkginfov1_content_hash(valid_pkginfov1: String) -> TestResult {
+        let pkg_info = PackageInfoV1::from_str(&valid_pkginfov1)?;
+
+        let reordered = valid_pkginfov1
+            .replace(
+                "depend = glibc\ndepend = gcc-libs",
+                "depend = gcc-libs\ndepend = glibc",
+            )
+            .replace(
+                "license = GPL-3.0-or-later\nlicense = LGPL-3.0-or-later",
+                "license = LGPL-3.0-or-later\nlicense = GPL-3.0-or-later",
+            );
+        assert_ne!(reordered, valid_pkginfov1);
+        assert_eq!(
+            PackageInfoV1::from_str(&reordered)?.content_hash(),
+            pkg_info.content_hash()
+        );
+
+        let changed = valid_pkginfov1.replace("depend = glibc", "depend = musl");
+        assert_ne!(
+            PackageInfoV1::from_str(&changed)?.content_hash(),
+            pkg_info.content_hash()
+        );
+        Ok(())
+    }
 }
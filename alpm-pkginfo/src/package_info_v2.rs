@@ -411,6 +411,21 @@ checkdepend = extra-test-tool
         Ok(())
     }
 
+    /// Ensure that a parsed PKGINFO is displayed exactly as the input, including extra data.
+    #[rstest]
+    #[case(VALID_PKGINFOV2_CASE1.to_string())]
+    #[case(format!("{VALID_PKGINFOV2_CASE1}\nxdata = foo=bar\nxdata = baz=qux"))]
+    fn pkginfov2_roundtrip(#[case] pkginfo: String) -> TestResult {
+        assert_eq!(PackageInfoV2::from_str(&pkginfo)?.to_string(), pkginfo);
+        Ok(())
+    }
+
+    #[rstest]
+    fn pkginfov2_unknown_key_fail() {
+        let pkginfo = format!("{VALID_PKGINFOV2_CASE1}\nfoo = bar");
+        assert!(PackageInfoV2::from_str(&pkginfo).is_err());
+    }
+
     #[rstest]
     fn pkginfov2_invalid_xdata_fail() -> TestResult {
         let mut pkg_info = pkg_info()?;
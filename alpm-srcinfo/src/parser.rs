@@ -6,7 +6,6 @@ use std::str::FromStr;
 
 use alpm_types::{
     Architecture,
-    Backup,
     Blake2b512Checksum,
     Changelog,
     Epoch,
@@ -735,7 +734,7 @@ impl SharedMetaProperty {
             .parse_next(input)?,
             SharedMetaKeyword::Backup => cut_err(
                 till_line_end
-                    .try_map(Backup::from_str)
+                    .try_map(RelativePath::from_str)
                     .map(SharedMetaProperty::Backup),
             )
             .parse_next(input)?,
@@ -952,6 +951,10 @@ impl SourceProperty {
             // Most notably, all checksums are `SKIP`pable, which means that we have to check at the
             // very first step if said checksum is to be skipped before we try to parse the input
             // as a checksum.
+            //
+            // The checksum type is always determined by the keyword and never by the length of the
+            // value, as the digests of some algorithms (i.e. Blake2b512 and Sha512) share the
+            // same length.
             SourceKeyword::B2sums
             | SourceKeyword::Md5sums
             | SourceKeyword::Sha1sums
@@ -986,7 +989,7 @@ impl SourceProperty {
                             architecture,
                             value: SkippableChecksum::Skip,
                         }),
-                        SourceKeyword::Sha512sums => SourceProperty::B2Checksum(ArchProperty {
+                        SourceKeyword::Sha512sums => SourceProperty::Sha512Checksum(ArchProperty {
                             architecture,
                             value: SkippableChecksum::Skip,
                         }),
@@ -1174,3 +1177,67 @@ impl ClearableProperty {
         Ok(property)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use testresult::TestResult;
+
+    use super::*;
+
+    const HEX_128: &str = "1816c57b4abf31eb7c57a66bfb0f0ee5cef9398b5e4cc303468e08dae2702da55978402da94673e444f8c02754e94dedef4d12450319383c3a481d1c5cd90c82";
+
+    /// Ensure that `b2sums` and `sha512sums` are never confused, even though their digests have
+    /// the same length.
+    #[test]
+    fn b2sums_and_sha512sums_with_same_digest() -> TestResult {
+        let b2sums = format!("b2sums = {HEX_128}\n");
+        let SourceProperty::B2Checksum(property) = SourceProperty::parser(&mut b2sums.as_str())?
+        else {
+            panic!("Expected b2sums to be parsed as Blake2b512 checksum");
+        };
+        assert_eq!(
+            property.value,
+            SkippableChecksum::Checksum {
+                digest: Blake2b512Checksum::from_str(HEX_128)?
+            }
+        );
+
+        let sha512sums = format!("sha512sums = {HEX_128}\n");
+        let SourceProperty::Sha512Checksum(property) =
+            SourceProperty::parser(&mut sha512sums.as_str())?
+        else {
+            panic!("Expected sha512sums to be parsed as Sha512 checksum");
+        };
+        assert_eq!(
+            property.value,
+            SkippableChecksum::Checksum {
+                digest: Sha512Checksum::from_str(HEX_128)?
+            }
+        );
+
+        Ok(())
+    }
+
+    /// Ensure that skipped checksums are assigned to the checksum type of their keyword.
+    #[rstest]
+    #[case("b2sums = SKIP\n")]
+    #[case("sha512sums = SKIP\n")]
+    #[case("sha512sums_x86_64 = SKIP\n")]
+    fn skipped_checksum_keeps_type(#[case] input: &str) -> TestResult {
+        let property = SourceProperty::parser(&mut &*input)?;
+        match property {
+            SourceProperty::B2Checksum(ArchProperty {
+                value: SkippableChecksum::Skip,
+                ..
+            }) if input.starts_with("b2sums") => {}
+            SourceProperty::Sha512Checksum(ArchProperty {
+                value: SkippableChecksum::Skip,
+                ..
+            }) if input.starts_with("sha512sums") => {}
+            property => panic!("Unexpected property for {input}: {property:?}"),
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::str::FromStr;
+
+use alpm_srcinfo::{MergedPackage, RelationOrSoname, SourceInfo};
+use alpm_types::{Architecture, MakepkgOption, Name, PackageRelation, SkippableChecksum, Version};
+use testresult::TestResult;
+
+/// A realistic SRCINFO of a split package with architecture specific sources and checksums.
+const SPLIT_PACKAGE_SRCINFO: &str = r#"
+pkgbase = example
+	pkgdesc = An example library and its tooling
+	pkgver = 1.2.3
+	pkgrel = 2
+	epoch = 1
+	url = https://example.org/
+	arch = x86_64
+	arch = aarch64
+	license = MIT
+	makedepends = cmake
+	makedepends = python>=3.12
+	checkdepends = python-pytest
+	depends = glibc
+	options = !lto
+	options = debug
+	source = https://example.org/example-1.2.3.tar.gz
+	sha256sums = 0db1b39fd70097c6733cdcce56b1559ece5521ec1aad9ee1d520dda73eff03d0
+	source_x86_64 = https://example.org/example-x86_64.patch
+	sha256sums_x86_64 = 2b7da5ec2e1d7e3b7f1f1e1bc63b3b1e1a2a1c3f5d7e9f0b1c3d5e7f9a1b3c5d
+	source_aarch64 = https://example.org/example-aarch64.patch
+	sha256sums_aarch64 = SKIP
+
+pkgname = example
+	depends = glibc
+	depends = gcc-libs
+
+pkgname = example-tools
+	pkgdesc = Tools for the example library
+	arch = x86_64
+	depends = example=1:1.2.3-2
+	depends_x86_64 = python
+"#;
+
+/// Ensure that a split package SRCINFO is parsed into its package base and package sections and
+/// that architecture specific properties are merged correctly.
+#[test]
+fn split_package() -> TestResult {
+    let source_info = SourceInfo::from_string(SPLIT_PACKAGE_SRCINFO)?.lint()?;
+
+    let base = &source_info.base;
+    assert_eq!(base.name, Name::new("example")?);
+    assert_eq!(base.version, Version::from_str("1:1.2.3-2")?);
+    assert_eq!(
+        base.options,
+        vec![MakepkgOption::new("!lto")?, MakepkgOption::new("debug")?]
+    );
+    assert_eq!(
+        base.make_dependencies,
+        vec![
+            PackageRelation::from_str("cmake")?,
+            PackageRelation::from_str("python>=3.12")?
+        ]
+    );
+    assert_eq!(base.sources.len(), 1);
+    assert_eq!(base.sha256_checksums.len(), 1);
+    for architecture in [Architecture::X86_64, Architecture::Aarch64] {
+        let properties = &base.architecture_properties[&architecture];
+        assert_eq!(properties.sources.len(), 1);
+        assert_eq!(properties.sha256_checksums.len(), 1);
+    }
+    assert!(matches!(
+        base.architecture_properties[&Architecture::Aarch64].sha256_checksums[0],
+        SkippableChecksum::Skip
+    ));
+
+    assert_eq!(
+        source_info
+            .packages
+            .iter()
+            .map(|package| package.name.to_string())
+            .collect::<Vec<_>>(),
+        vec!["example", "example-tools"]
+    );
+
+    // The tools package only exists for `x86_64`.
+    let aarch64 = source_info
+        .packages_for_architecture(Architecture::Aarch64)
+        .collect::<Vec<MergedPackage>>();
+    assert_eq!(aarch64.len(), 1);
+
+    let x86_64 = source_info
+        .packages_for_architecture(Architecture::X86_64)
+        .collect::<Vec<MergedPackage>>();
+    assert_eq!(x86_64.len(), 2);
+
+    let tools = &x86_64[1];
+    assert_eq!(
+        tools.description,
+        Some("Tools for the example library".to_string())
+    );
+    assert_eq!(
+        tools.dependencies,
+        vec![
+            RelationOrSoname::Relation(PackageRelation::from_str("example=1:1.2.3-2")?),
+            RelationOrSoname::Relation(PackageRelation::from_str("python")?),
+        ]
+    );
+    // Sources of the package base and the architecture specific ones are combined.
+    assert_eq!(tools.sources.len(), 2);
+
+    Ok(())
+}
+
+/// Ensure that malformed input results in an error that points to the offending line.
+#[test]
+fn split_package_invalid_line() -> TestResult {
+    let input = SPLIT_PACKAGE_SRCINFO.replace("\tpkgrel = 2", "\tpkgrel 2");
+    let Err(error) = SourceInfo::from_string(&input) else {
+        panic!("Parsing succeeded even though a line is malformed.");
+    };
+    let error = error.to_string();
+    // The input starts with an empty line, which places `pkgrel` on the fifth line.
+    assert!(
+        error.contains("parse error at line 5"),
+        "Unexpected error: {error}"
+    );
+    assert!(
+        error.contains("5 | \tpkgrel 2"),
+        "Unexpected error: {error}"
+    );
+
+    Ok(())
+}
@@ -1,15 +1,21 @@
 use std::{
     fmt::{Debug, Display, Formatter},
+    fs::File,
+    hash::{Hash, Hasher},
+    io::{self, Read},
     marker::PhantomData,
+    path::Path,
     str::FromStr,
 };
 
 pub use digest::Digest;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use subtle::ConstantTimeEq;
 
 use crate::{
     Error,
     digests::{Blake2b512, Md5, Sha1, Sha224, Sha256, Sha384, Sha512},
+    parse::deserialize_from_str,
 };
 
 // Convenience type aliases for the supported checksums
@@ -35,6 +41,215 @@ pub type Sha384Checksum = Checksum<Sha384>;
 /// A checksum using the Sha512 algorithm
 pub type Sha512Checksum = Checksum<Sha512>;
 
+/// A supported checksum algorithm
+///
+/// Each variant corresponds to one of the digests that can be used with [`Checksum`] and to one of
+/// the checksum arrays in a `PKGBUILD` (e.g. `md5sums` or `sha256sums`).
+///
+/// ## Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::ChecksumAlgorithm;
+///
+/// assert_eq!(
+///     ChecksumAlgorithm::from_str("md5"),
+///     Ok(ChecksumAlgorithm::Md5)
+/// );
+/// assert_eq!("sha256", format!("{}", ChecksumAlgorithm::Sha256));
+/// ```
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    strum::Display,
+    strum::EnumString,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[non_exhaustive]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+pub enum ChecksumAlgorithm {
+    /// The Blake2b512 algorithm
+    Blake2b512,
+    /// The Md5 algorithm
+    Md5,
+    /// The Sha1 algorithm
+    Sha1,
+    /// The Sha224 algorithm
+    Sha224,
+    /// The Sha256 algorithm
+    Sha256,
+    /// The Sha384 algorithm
+    Sha384,
+    /// The Sha512 algorithm
+    Sha512,
+}
+
+impl ChecksumAlgorithm {
+    /// Returns `true` if the algorithm is considered cryptographically weak.
+    ///
+    /// This is the case for [`ChecksumAlgorithm::Md5`] and [`ChecksumAlgorithm::Sha1`].
+    /// Checksums using these algorithms are still accepted for backwards compatibility, but
+    /// consumers may want to warn about their use.
+    ///
+    /// ## Examples
+    /// ```
+    /// use alpm_types::ChecksumAlgorithm;
+    ///
+    /// assert!(ChecksumAlgorithm::Md5.is_cryptographically_weak());
+    /// assert!(!ChecksumAlgorithm::Sha512.is_cryptographically_weak());
+    /// ```
+    pub fn is_cryptographically_weak(&self) -> bool {
+        matches!(self, ChecksumAlgorithm::Md5 | ChecksumAlgorithm::Sha1)
+    }
+
+    /// Returns the number of hexadecimal characters of a checksum using the algorithm.
+    ///
+    /// ## Examples
+    /// ```
+    /// use alpm_types::ChecksumAlgorithm;
+    ///
+    /// assert_eq!(ChecksumAlgorithm::Md5.hex_length(), 32);
+    /// assert_eq!(ChecksumAlgorithm::Sha256.hex_length(), 64);
+    /// ```
+    pub fn hex_length(&self) -> usize {
+        let output_size = match self {
+            ChecksumAlgorithm::Blake2b512 => <Blake2b512 as Digest>::output_size(),
+            ChecksumAlgorithm::Md5 => <Md5 as Digest>::output_size(),
+            ChecksumAlgorithm::Sha1 => <Sha1 as Digest>::output_size(),
+            ChecksumAlgorithm::Sha224 => <Sha224 as Digest>::output_size(),
+            ChecksumAlgorithm::Sha256 => <Sha256 as Digest>::output_size(),
+            ChecksumAlgorithm::Sha384 => <Sha384 as Digest>::output_size(),
+            ChecksumAlgorithm::Sha512 => <Sha512 as Digest>::output_size(),
+        };
+        output_size * 2
+    }
+
+    /// Returns the keyword used for checksums of the algorithm in a `.SRCINFO` file.
+    ///
+    /// ## Examples
+    /// ```
+    /// use alpm_types::ChecksumAlgorithm;
+    ///
+    /// assert_eq!(ChecksumAlgorithm::Blake2b512.srcinfo_keyword(), "b2sums");
+    /// assert_eq!(ChecksumAlgorithm::Sha256.srcinfo_keyword(), "sha256sums");
+    /// ```
+    pub fn srcinfo_keyword(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Blake2b512 => "b2sums",
+            ChecksumAlgorithm::Md5 => "md5sums",
+            ChecksumAlgorithm::Sha1 => "sha1sums",
+            ChecksumAlgorithm::Sha224 => "sha224sums",
+            ChecksumAlgorithm::Sha256 => "sha256sums",
+            ChecksumAlgorithm::Sha384 => "sha384sums",
+            ChecksumAlgorithm::Sha512 => "sha512sums",
+        }
+    }
+}
+
+/// A policy on which [`ChecksumAlgorithm`]s are required for the sources of a package
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{ChecksumAlgorithm, ChecksumPolicy};
+///
+/// let policy = ChecksumPolicy::new(vec![ChecksumAlgorithm::Sha256]);
+/// assert_eq!(policy.required(), &[ChecksumAlgorithm::Sha256]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct ChecksumPolicy {
+    required: Vec<ChecksumAlgorithm>,
+}
+
+impl ChecksumPolicy {
+    /// Creates a new [`ChecksumPolicy`] from a list of required [`ChecksumAlgorithm`]s
+    pub fn new(required: Vec<ChecksumAlgorithm>) -> Self {
+        Self { required }
+    }
+
+    /// Returns the required [`ChecksumAlgorithm`]s
+    pub fn required(&self) -> &[ChecksumAlgorithm] {
+        &self.required
+    }
+}
+
+/// The status of a single checksum entry, as returned by [`classify_checksum_entry`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChecksumEntryStatus {
+    /// The entry is the `SKIP` keyword.
+    Skip,
+    /// The entry is a valid hexadecimal checksum for the algorithm.
+    Valid,
+    /// The entry has a length that does not match the algorithm.
+    WrongLength {
+        /// The number of characters expected for the algorithm.
+        expected: usize,
+        /// The number of characters of the entry.
+        got: usize,
+    },
+    /// The entry contains a character that is not a hexadecimal digit.
+    NonHex {
+        /// The (zero-based) position of the first offending character.
+        position: usize,
+    },
+}
+
+/// Classifies a single checksum entry for a given [`ChecksumAlgorithm`]
+///
+/// Contrary to parsing a [`SkippableChecksum`], this provides the exact reason for why an entry is
+/// invalid, which allows linters to point at the problem.
+/// Non-hexadecimal characters are reported before a wrong length.
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{ChecksumAlgorithm, ChecksumEntryStatus, classify_checksum_entry};
+///
+/// assert_eq!(
+///     classify_checksum_entry(ChecksumAlgorithm::Md5, "SKIP"),
+///     ChecksumEntryStatus::Skip
+/// );
+/// assert_eq!(
+///     classify_checksum_entry(ChecksumAlgorithm::Md5, "d41d8cd98f00b204e9800998ecf8427e"),
+///     ChecksumEntryStatus::Valid
+/// );
+/// assert_eq!(
+///     classify_checksum_entry(ChecksumAlgorithm::Md5, "d41d8cd9"),
+///     ChecksumEntryStatus::WrongLength {
+///         expected: 32,
+///         got: 8
+///     }
+/// );
+/// assert_eq!(
+///     classify_checksum_entry(ChecksumAlgorithm::Md5, "d41d8cx9"),
+///     ChecksumEntryStatus::NonHex { position: 6 }
+/// );
+/// ```
+pub fn classify_checksum_entry(algorithm: ChecksumAlgorithm, value: &str) -> ChecksumEntryStatus {
+    if value == "SKIP" {
+        return ChecksumEntryStatus::Skip;
+    }
+
+    if let Some(position) = value.chars().position(|char| !char.is_ascii_hexdigit()) {
+        return ChecksumEntryStatus::NonHex { position };
+    }
+
+    let expected = algorithm.hex_length();
+    if value.len() != expected {
+        return ChecksumEntryStatus::WrongLength {
+            expected,
+            got: value.len(),
+        };
+    }
+
+    ChecksumEntryStatus::Valid
+}
+
 /// A [checksum] using a supported algorithm
 ///
 /// Checksums are created using one of the supported algorithms:
@@ -110,7 +325,7 @@ pub type Sha512Checksum = Checksum<Sha512>;
 #[derive(Clone)]
 pub struct Checksum<D: Digest> {
     digest: Vec<u8>,
-    _marker: PhantomData<*const D>,
+    _marker: PhantomData<fn() -> D>,
 }
 
 impl<D: Digest> Serialize for Checksum<D> {
@@ -126,6 +341,16 @@ impl<D: Digest> Serialize for Checksum<D> {
     }
 }
 
+impl<'de, D: Digest> Deserialize<'de> for Checksum<D> {
+    /// Deserialize a [`Checksum`] from its hex `String` representation.
+    fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+    where
+        De: Deserializer<'de>,
+    {
+        deserialize_from_str(deserializer)
+    }
+}
+
 impl<D: Digest> Checksum<D> {
     /// Calculate a new Checksum for data that may be represented as a list of bytes
     ///
@@ -148,10 +373,91 @@ impl<D: Digest> Checksum<D> {
         }
     }
 
+    /// Calculate a new Checksum for data provided by a reader
+    ///
+    /// The data is read in chunks of 64 KiB, which allows to calculate checksums for large amounts
+    /// of data without having to keep all of it in memory.
+    /// The resulting Checksum is identical to one created using [`Checksum::calculate_from`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if reading from `reader` fails.
+    ///
+    /// ## Examples
+    /// ```
+    /// use alpm_types::{Checksum, digests::Sha256};
+    ///
+    /// # fn main() -> std::io::Result<()> {
+    /// assert_eq!(
+    ///     Checksum::<Sha256>::from_reader("foo\n".as_bytes())?,
+    ///     Checksum::<Sha256>::calculate_from("foo\n"),
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_reader<R: Read>(reader: R) -> io::Result<Self> {
+        let mut hasher = D::new();
+        read_chunks(reader, |data| hasher.update(data))?;
+
+        Ok(Checksum {
+            digest: hasher.finalize()[..].to_vec(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Calculate a new Checksum for the contents of a file
+    ///
+    /// This is a convenience wrapper around [`Checksum::from_reader`].
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the file can not be opened or read.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_reader(File::open(path)?)
+    }
+
     /// Return a reference to the inner type
     pub fn inner(&self) -> &[u8] {
         &self.digest
     }
+
+    /// Return the raw bytes of the digest
+    ///
+    /// This is an alias for [`Checksum::inner`] and the inverse of creating a Checksum from a byte
+    /// slice using [`TryFrom`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use alpm_types::{Checksum, digests::Md5};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let checksum = Checksum::<Md5>::calculate_from("foo\n");
+    /// assert_eq!(Checksum::<Md5>::try_from(checksum.as_bytes())?, checksum);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inner()
+    }
+
+    /// Compares the Checksum with another in constant time
+    ///
+    /// Contrary to the [`PartialEq`] implementation, which returns early on the first differing
+    /// byte, the time this comparison takes does not depend on how many bytes of the two digests
+    /// match.
+    /// This method should be used when comparing against checksums of untrusted input.
+    ///
+    /// ## Examples
+    /// ```
+    /// use alpm_types::{Checksum, digests::Sha256};
+    ///
+    /// let checksum = Checksum::<Sha256>::calculate_from("foo\n");
+    /// assert!(checksum.ct_eq(&Checksum::<Sha256>::calculate_from("foo\n")));
+    /// assert!(!checksum.ct_eq(&Checksum::<Sha256>::calculate_from("bar\n")));
+    /// ```
+    pub fn ct_eq(&self, other: &Checksum<D>) -> bool {
+        self.digest.as_slice().ct_eq(other.digest.as_slice()).into()
+    }
 }
 
 impl<D: Digest> FromStr for Checksum<D> {
@@ -203,6 +509,43 @@ impl<D: Digest> FromStr for Checksum<D> {
     }
 }
 
+impl<D: Digest> TryFrom<&[u8]> for Checksum<D> {
+    type Error = Error;
+
+    /// Create a new Checksum from the raw bytes of a digest
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error::IncorrectLength`] if the number of bytes does not match the output size
+    /// of the algorithm.
+    ///
+    /// ## Examples
+    /// ```
+    /// use alpm_types::{Checksum, digests::Md5};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let checksum = Checksum::<Md5>::try_from([0u8; 16].as_slice())?;
+    /// assert_eq!(checksum.to_string(), "00000000000000000000000000000000");
+    ///
+    /// assert!(Checksum::<Md5>::try_from([0u8; 20].as_slice()).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        if bytes.len() != <D as Digest>::output_size() {
+            return Err(Error::IncorrectLength {
+                length: bytes.len(),
+                expected: <D as Digest>::output_size(),
+            });
+        }
+
+        Ok(Checksum {
+            digest: bytes.to_vec(),
+            _marker: PhantomData,
+        })
+    }
+}
+
 impl<D: Digest> Display for Checksum<D> {
     fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
         write!(
@@ -231,11 +574,19 @@ impl<D: Digest> PartialEq for Checksum<D> {
     }
 }
 
+impl<D: Digest> Eq for Checksum<D> {}
+
+impl<D: Digest> Hash for Checksum<D> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.digest.hash(state);
+    }
+}
+
 /// A [`Checksum`] that may be skipped.
 ///
 /// Strings representing checksums are used to verify the integrity of files.
 /// If the `"SKIP"` keyword is found, the integrity check is skipped.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub enum SkippableChecksum<D: Digest + Clone> {
     /// Sourcefile checksum validation may be skipped, which is expressed with this variant.
@@ -248,6 +599,78 @@ pub enum SkippableChecksum<D: Digest + Clone> {
     },
 }
 
+impl<D: Digest + Clone> SkippableChecksum<D> {
+    /// Verifies data against the checksum
+    ///
+    /// If the checksum is [`SkippableChecksum::Skip`], verification is skipped (like makepkg
+    /// does).
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error::ChecksumMismatch`] if the checksum of `data` does not match.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{SkippableChecksum, digests::Sha256};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let checksum = SkippableChecksum::<Sha256>::from_str(
+    ///     "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c",
+    /// )?;
+    /// assert!(checksum.verify(b"foo\n").is_ok());
+    /// assert!(checksum.verify(b"bar\n").is_err());
+    /// assert!(SkippableChecksum::<Sha256>::Skip.verify(b"bar\n").is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verify(&self, data: &[u8]) -> Result<(), Error> {
+        match self {
+            SkippableChecksum::Skip => Ok(()),
+            SkippableChecksum::Checksum { digest } => {
+                Self::compare(digest, &Checksum::calculate_from(data))
+            }
+        }
+    }
+
+    /// Verifies data provided by a reader against the checksum
+    ///
+    /// If the checksum is [`SkippableChecksum::Skip`], verification is skipped and `reader` is not
+    /// read from.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - reading from `reader` fails,
+    /// - or the checksum of the data does not match.
+    pub fn verify_reader<R: Read>(&self, reader: R) -> Result<(), Error> {
+        match self {
+            SkippableChecksum::Skip => Ok(()),
+            SkippableChecksum::Checksum { digest } => {
+                let actual = Checksum::from_reader(reader).map_err(|error| Error::IoError {
+                    context: "reading the data to verify",
+                    source: error.into(),
+                })?;
+                Self::compare(digest, &actual)
+            }
+        }
+    }
+
+    /// Compares an expected and an actual [`Checksum`]
+    fn compare(expected: &Checksum<D>, actual: &Checksum<D>) -> Result<(), Error> {
+        if !expected.ct_eq(actual) {
+            return Err(Error::ChecksumMismatch {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
 impl<D: Digest + Clone> FromStr for SkippableChecksum<D> {
     type Err = Error;
     /// Create a new [`SkippableChecksum`] from a string slice and return it in a Result.
@@ -307,13 +730,639 @@ impl<D: Digest + Clone> PartialEq for SkippableChecksum<D> {
     }
 }
 
+/// A checksum of any of the supported algorithms, or the `SKIP` keyword
+///
+/// The algorithm of an [`AnyChecksum`] is detected based on the length of a hexadecimal string.
+///
+/// Note that [`Blake2b512`] and [`Sha512`] produce digests of the same length.
+/// As the two can not be distinguished, such checksums are detected as [`Sha512`].
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{AnyChecksum, ChecksumAlgorithm};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let checksum = AnyChecksum::from_hex("d41d8cd98f00b204e9800998ecf8427e")?;
+/// assert_eq!(checksum.algorithm(), Some(ChecksumAlgorithm::Md5));
+/// assert_eq!(AnyChecksum::from_hex("SKIP")?, AnyChecksum::Skip);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AnyChecksum {
+    /// Checksum validation is skipped.
+    Skip,
+    /// A checksum using the Md5 algorithm.
+    Md5(Md5Checksum),
+    /// A checksum using the Sha1 algorithm.
+    Sha1(Sha1Checksum),
+    /// A checksum using the Sha224 algorithm.
+    Sha224(Sha224Checksum),
+    /// A checksum using the Sha256 algorithm.
+    Sha256(Sha256Checksum),
+    /// A checksum using the Sha384 algorithm.
+    Sha384(Sha384Checksum),
+    /// A checksum using the Sha512 algorithm.
+    Sha512(Sha512Checksum),
+}
+
+impl AnyChecksum {
+    /// Creates an [`AnyChecksum`] from a hexadecimal string or the `SKIP` keyword.
+    ///
+    /// The algorithm is chosen based on the number of bytes the hexadecimal string represents.
+    /// Whitespaces are removed from the input, as done by [`Checksum::from_str`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the length of `s` does not match any of the supported algorithms,
+    /// - or `s` is not a valid hexadecimal string.
+    pub fn from_hex(s: &str) -> Result<Self, Error> {
+        if s.trim() == "SKIP" {
+            return Ok(AnyChecksum::Skip);
+        }
+
+        let input = s.replace(' ', "");
+        let algorithm = [
+            ChecksumAlgorithm::Md5,
+            ChecksumAlgorithm::Sha1,
+            ChecksumAlgorithm::Sha224,
+            ChecksumAlgorithm::Sha256,
+            ChecksumAlgorithm::Sha384,
+            ChecksumAlgorithm::Sha512,
+        ]
+        .into_iter()
+        .find(|algorithm| algorithm.hex_length() == input.len())
+        .ok_or(Error::UnknownChecksumLength {
+            length: input.len(),
+        })?;
+
+        Ok(match algorithm {
+            ChecksumAlgorithm::Md5 => AnyChecksum::Md5(Checksum::from_str(&input)?),
+            ChecksumAlgorithm::Sha1 => AnyChecksum::Sha1(Checksum::from_str(&input)?),
+            ChecksumAlgorithm::Sha224 => AnyChecksum::Sha224(Checksum::from_str(&input)?),
+            ChecksumAlgorithm::Sha256 => AnyChecksum::Sha256(Checksum::from_str(&input)?),
+            ChecksumAlgorithm::Sha384 => AnyChecksum::Sha384(Checksum::from_str(&input)?),
+            ChecksumAlgorithm::Blake2b512 | ChecksumAlgorithm::Sha512 => {
+                AnyChecksum::Sha512(Checksum::from_str(&input)?)
+            }
+        })
+    }
+
+    /// Returns the detected [`ChecksumAlgorithm`], or [`None`] if the checksum is skipped.
+    pub fn algorithm(&self) -> Option<ChecksumAlgorithm> {
+        match self {
+            AnyChecksum::Skip => None,
+            AnyChecksum::Md5(_) => Some(ChecksumAlgorithm::Md5),
+            AnyChecksum::Sha1(_) => Some(ChecksumAlgorithm::Sha1),
+            AnyChecksum::Sha224(_) => Some(ChecksumAlgorithm::Sha224),
+            AnyChecksum::Sha256(_) => Some(ChecksumAlgorithm::Sha256),
+            AnyChecksum::Sha384(_) => Some(ChecksumAlgorithm::Sha384),
+            AnyChecksum::Sha512(_) => Some(ChecksumAlgorithm::Sha512),
+        }
+    }
+}
+
+impl FromStr for AnyChecksum {
+    type Err = Error;
+    /// Create an [`AnyChecksum`] from a string slice, using [`AnyChecksum::from_hex`].
+    fn from_str(s: &str) -> Result<AnyChecksum, Self::Err> {
+        AnyChecksum::from_hex(s)
+    }
+}
+
+impl Display for AnyChecksum {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AnyChecksum::Skip => write!(fmt, "SKIP"),
+            AnyChecksum::Md5(checksum) => write!(fmt, "{checksum}"),
+            AnyChecksum::Sha1(checksum) => write!(fmt, "{checksum}"),
+            AnyChecksum::Sha224(checksum) => write!(fmt, "{checksum}"),
+            AnyChecksum::Sha256(checksum) => write!(fmt, "{checksum}"),
+            AnyChecksum::Sha384(checksum) => write!(fmt, "{checksum}"),
+            AnyChecksum::Sha512(checksum) => write!(fmt, "{checksum}"),
+        }
+    }
+}
+
+/// Checksums of the same data using several algorithms
+///
+/// This allows calculating checksums using several [`ChecksumAlgorithm`]s while reading the data
+/// only once (see [`MultiChecksum::from_reader`]).
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{Checksum, ChecksumAlgorithm, MultiChecksum, digests::Md5};
+///
+/// # fn main() -> std::io::Result<()> {
+/// let checksums = MultiChecksum::from_reader(
+///     "foo\n".as_bytes(),
+///     &[ChecksumAlgorithm::Md5, ChecksumAlgorithm::Sha256],
+/// )?;
+/// assert_eq!(
+///     checksums.md5(),
+///     Some(&Checksum::<Md5>::calculate_from("foo\n"))
+/// );
+/// assert!(checksums.sha256().is_some());
+/// assert!(checksums.sha512().is_none());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultiChecksum {
+    blake2b512: Option<Blake2b512Checksum>,
+    md5: Option<Md5Checksum>,
+    sha1: Option<Sha1Checksum>,
+    sha224: Option<Sha224Checksum>,
+    sha256: Option<Sha256Checksum>,
+    sha384: Option<Sha384Checksum>,
+    sha512: Option<Sha512Checksum>,
+}
+
+impl MultiChecksum {
+    /// Calculate the checksums of the data provided by a reader using several algorithms
+    ///
+    /// The data is read only once and all hashers of the selected `algorithms` are updated
+    /// simultaneously.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if reading from `reader` fails.
+    pub fn from_reader<R: Read>(reader: R, algorithms: &[ChecksumAlgorithm]) -> io::Result<Self> {
+        fn hasher<D: Digest>(
+            algorithms: &[ChecksumAlgorithm],
+            algorithm: ChecksumAlgorithm,
+        ) -> Option<D> {
+            algorithms.contains(&algorithm).then(D::new)
+        }
+        fn update<D: Digest>(hasher: &mut Option<D>, data: &[u8]) {
+            if let Some(hasher) = hasher {
+                hasher.update(data);
+            }
+        }
+        fn finalize<D: Digest>(hasher: Option<D>) -> Option<Checksum<D>> {
+            hasher.map(|hasher| Checksum {
+                digest: hasher.finalize()[..].to_vec(),
+                _marker: PhantomData,
+            })
+        }
+
+        let mut blake2b512 = hasher::<Blake2b512>(algorithms, ChecksumAlgorithm::Blake2b512);
+        let mut md5 = hasher::<Md5>(algorithms, ChecksumAlgorithm::Md5);
+        let mut sha1 = hasher::<Sha1>(algorithms, ChecksumAlgorithm::Sha1);
+        let mut sha224 = hasher::<Sha224>(algorithms, ChecksumAlgorithm::Sha224);
+        let mut sha256 = hasher::<Sha256>(algorithms, ChecksumAlgorithm::Sha256);
+        let mut sha384 = hasher::<Sha384>(algorithms, ChecksumAlgorithm::Sha384);
+        let mut sha512 = hasher::<Sha512>(algorithms, ChecksumAlgorithm::Sha512);
+
+        read_chunks(reader, |data| {
+            update(&mut blake2b512, data);
+            update(&mut md5, data);
+            update(&mut sha1, data);
+            update(&mut sha224, data);
+            update(&mut sha256, data);
+            update(&mut sha384, data);
+            update(&mut sha512, data);
+        })?;
+
+        Ok(MultiChecksum {
+            blake2b512: finalize(blake2b512),
+            md5: finalize(md5),
+            sha1: finalize(sha1),
+            sha224: finalize(sha224),
+            sha256: finalize(sha256),
+            sha384: finalize(sha384),
+            sha512: finalize(sha512),
+        })
+    }
+
+    /// Returns the checksum using the Blake2b512 algorithm, if it has been calculated.
+    pub fn blake2b512(&self) -> Option<&Blake2b512Checksum> {
+        self.blake2b512.as_ref()
+    }
+
+    /// Returns the checksum using the Md5 algorithm, if it has been calculated.
+    pub fn md5(&self) -> Option<&Md5Checksum> {
+        self.md5.as_ref()
+    }
+
+    /// Returns the checksum using the Sha1 algorithm, if it has been calculated.
+    pub fn sha1(&self) -> Option<&Sha1Checksum> {
+        self.sha1.as_ref()
+    }
+
+    /// Returns the checksum using the Sha224 algorithm, if it has been calculated.
+    pub fn sha224(&self) -> Option<&Sha224Checksum> {
+        self.sha224.as_ref()
+    }
+
+    /// Returns the checksum using the Sha256 algorithm, if it has been calculated.
+    pub fn sha256(&self) -> Option<&Sha256Checksum> {
+        self.sha256.as_ref()
+    }
+
+    /// Returns the checksum using the Sha384 algorithm, if it has been calculated.
+    pub fn sha384(&self) -> Option<&Sha384Checksum> {
+        self.sha384.as_ref()
+    }
+
+    /// Returns the checksum using the Sha512 algorithm, if it has been calculated.
+    pub fn sha512(&self) -> Option<&Sha512Checksum> {
+        self.sha512.as_ref()
+    }
+}
+
+/// Renders a list of [`SkippableChecksum`]s as the checksum lines of a `.SRCINFO` file
+///
+/// Each checksum is rendered on its own line, indented by a tab and prefixed with the
+/// [`ChecksumAlgorithm::srcinfo_keyword`] of `algorithm`.
+/// Skipped checksums are rendered as `SKIP`.
+///
+/// ## Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{ChecksumAlgorithm, SkippableChecksum, checksums_to_srcinfo, digests::Md5};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let checksums = [
+///     SkippableChecksum::<Md5>::from_str("d41d8cd98f00b204e9800998ecf8427e")?,
+///     SkippableChecksum::<Md5>::Skip,
+/// ];
+/// assert_eq!(
+///     checksums_to_srcinfo(ChecksumAlgorithm::Md5, &checksums),
+///     "\tmd5sums = d41d8cd98f00b204e9800998ecf8427e\n\tmd5sums = SKIP\n"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn checksums_to_srcinfo<D: Digest + Clone>(
+    algorithm: ChecksumAlgorithm,
+    checksums: &[SkippableChecksum<D>],
+) -> String {
+    let keyword = algorithm.srcinfo_keyword();
+    checksums
+        .iter()
+        .map(|checksum| format!("\t{keyword} = {checksum}\n"))
+        .collect()
+}
+
+/// Reads all data from a reader in chunks and passes each chunk to `update`
+///
+/// Reads that are interrupted are retried.
+///
+/// ## Errors
+///
+/// Returns an error if reading from `reader` fails.
+fn read_chunks<R: Read>(mut reader: R, mut update: impl FnMut(&[u8])) -> io::Result<()> {
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        update(&buffer[..read]);
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::io::Write;
+
     use proptest::prelude::*;
     use rstest::rstest;
+    use testresult::TestResult;
 
     use super::*;
 
+    #[test]
+    fn checksum_try_from_bytes() -> TestResult {
+        let bytes = [
+            0xd4, 0x1d, 0x8c, 0xd9, 0x8f, 0x00, 0xb2, 0x04, 0xe9, 0x80, 0x09, 0x98, 0xec, 0xf8,
+            0x42, 0x7e,
+        ];
+        let checksum = Md5Checksum::try_from(bytes.as_slice())?;
+        assert_eq!(
+            checksum,
+            Md5Checksum::from_str("d41d8cd98f00b204e9800998ecf8427e")?
+        );
+        assert_eq!(checksum.as_bytes(), bytes);
+
+        let checksum = Sha256Checksum::calculate_from("foo\n");
+        assert_eq!(Sha256Checksum::try_from(checksum.as_bytes())?, checksum);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::too_short(15)]
+    #[case::too_long(17)]
+    #[case::empty(0)]
+    #[case::sha1_size(20)]
+    fn checksum_try_from_bytes_incorrect_length(#[case] length: usize) {
+        assert_eq!(
+            Md5Checksum::try_from(vec![0; length].as_slice()),
+            Err(Error::IncorrectLength {
+                length,
+                expected: 16
+            })
+        );
+    }
+
+    #[rstest]
+    #[case::empty(b"", &[ChecksumAlgorithm::Md5, ChecksumAlgorithm::Sha256])]
+    #[case::text(b"foo\n", &[ChecksumAlgorithm::Md5, ChecksumAlgorithm::Sha256, ChecksumAlgorithm::Blake2b512])]
+    #[case::all(b"foo\n", &[
+        ChecksumAlgorithm::Blake2b512,
+        ChecksumAlgorithm::Md5,
+        ChecksumAlgorithm::Sha1,
+        ChecksumAlgorithm::Sha224,
+        ChecksumAlgorithm::Sha256,
+        ChecksumAlgorithm::Sha384,
+        ChecksumAlgorithm::Sha512,
+    ])]
+    #[case::none(b"foo\n", &[])]
+    fn multi_checksum_from_reader(
+        #[case] data: &[u8],
+        #[case] algorithms: &[ChecksumAlgorithm],
+    ) -> TestResult {
+        // Use more data than fits into the read buffer.
+        let data = data.repeat(100_000);
+        let checksums = MultiChecksum::from_reader(data.as_slice(), algorithms)?;
+
+        let expected = |algorithm: ChecksumAlgorithm| algorithms.contains(&algorithm);
+        assert_eq!(
+            checksums.blake2b512(),
+            expected(ChecksumAlgorithm::Blake2b512)
+                .then(|| Checksum::<Blake2b512>::calculate_from(&data))
+                .as_ref()
+        );
+        assert_eq!(
+            checksums.md5(),
+            expected(ChecksumAlgorithm::Md5)
+                .then(|| Checksum::<Md5>::calculate_from(&data))
+                .as_ref()
+        );
+        assert_eq!(
+            checksums.sha1(),
+            expected(ChecksumAlgorithm::Sha1)
+                .then(|| Checksum::<Sha1>::calculate_from(&data))
+                .as_ref()
+        );
+        assert_eq!(
+            checksums.sha224(),
+            expected(ChecksumAlgorithm::Sha224)
+                .then(|| Checksum::<Sha224>::calculate_from(&data))
+                .as_ref()
+        );
+        assert_eq!(
+            checksums.sha256(),
+            expected(ChecksumAlgorithm::Sha256)
+                .then(|| Checksum::<Sha256>::calculate_from(&data))
+                .as_ref()
+        );
+        assert_eq!(
+            checksums.sha384(),
+            expected(ChecksumAlgorithm::Sha384)
+                .then(|| Checksum::<Sha384>::calculate_from(&data))
+                .as_ref()
+        );
+        assert_eq!(
+            checksums.sha512(),
+            expected(ChecksumAlgorithm::Sha512)
+                .then(|| Checksum::<Sha512>::calculate_from(&data))
+                .as_ref()
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::equal("foo", "foo")]
+    #[case::first_byte_differs("foo", "bar")]
+    #[case::empty("", "")]
+    #[case::one_empty("", "foo")]
+    fn checksum_ct_eq(#[case] left: &str, #[case] right: &str) {
+        let left = Sha256Checksum::calculate_from(left);
+        let right = Sha256Checksum::calculate_from(right);
+        assert_eq!(left.ct_eq(&right), left == right);
+        assert_eq!(right.ct_eq(&left), left == right);
+    }
+
+    #[test]
+    fn checksum_ct_eq_last_byte_differs() -> TestResult {
+        let left = Md5Checksum::from_str("d41d8cd98f00b204e9800998ecf8427e")?;
+        let right = Md5Checksum::from_str("d41d8cd98f00b204e9800998ecf8427f")?;
+        assert!(!left.ct_eq(&right));
+        assert_eq!(left.ct_eq(&right), left == right);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::skip("SKIP", None)]
+    #[case::md5("d41d8cd98f00b204e9800998ecf8427e", Some(ChecksumAlgorithm::Md5))]
+    #[case::sha1(
+        "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+        Some(ChecksumAlgorithm::Sha1)
+    )]
+    #[case::sha224(
+        "d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f",
+        Some(ChecksumAlgorithm::Sha224)
+    )]
+    #[case::sha256(
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+        Some(ChecksumAlgorithm::Sha256)
+    )]
+    #[case::sha384(
+        "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da274edebfe76f65fbd51ad2f14898b95b",
+        Some(ChecksumAlgorithm::Sha384)
+    )]
+    #[case::sha512(
+        "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e",
+        Some(ChecksumAlgorithm::Sha512)
+    )]
+    fn any_checksum_from_hex(
+        #[case] input: &str,
+        #[case] algorithm: Option<ChecksumAlgorithm>,
+    ) -> TestResult {
+        let checksum = AnyChecksum::from_hex(input)?;
+        assert_eq!(checksum.algorithm(), algorithm);
+        assert_eq!(checksum.to_string(), input);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::ten_bytes("00112233445566778899", Error::UnknownChecksumLength { length: 20 })]
+    #[case::empty("", Error::UnknownChecksumLength { length: 0 })]
+    #[case::non_hex(
+        "d41d8cd98f00b204e9800998ecf8427x",
+        Error::InvalidInteger { kind: std::num::IntErrorKind::InvalidDigit }
+    )]
+    fn any_checksum_from_hex_fail(#[case] input: &str, #[case] error: Error) {
+        assert_eq!(AnyChecksum::from_hex(input), Err(error));
+    }
+
+    #[rstest]
+    #[case(ChecksumAlgorithm::Blake2b512, false)]
+    #[case(ChecksumAlgorithm::Md5, true)]
+    #[case(ChecksumAlgorithm::Sha1, true)]
+    #[case(ChecksumAlgorithm::Sha224, false)]
+    #[case(ChecksumAlgorithm::Sha256, false)]
+    #[case(ChecksumAlgorithm::Sha384, false)]
+    #[case(ChecksumAlgorithm::Sha512, false)]
+    fn checksum_algorithm_is_cryptographically_weak(
+        #[case] algorithm: ChecksumAlgorithm,
+        #[case] weak: bool,
+    ) {
+        assert_eq!(algorithm.is_cryptographically_weak(), weak);
+    }
+
+    #[rstest]
+    #[case::skip("SKIP", ChecksumEntryStatus::Skip)]
+    #[case::valid("d41d8cd98f00b204e9800998ecf8427e", ChecksumEntryStatus::Valid)]
+    #[case::valid_uppercase("D41D8CD98F00B204E9800998ECF8427E", ChecksumEntryStatus::Valid)]
+    #[case::too_short("d41d8cd9", ChecksumEntryStatus::WrongLength { expected: 32, got: 8 })]
+    #[case::too_long(
+        "d41d8cd98f00b204e9800998ecf8427e00",
+        ChecksumEntryStatus::WrongLength { expected: 32, got: 34 },
+    )]
+    #[case::empty("", ChecksumEntryStatus::WrongLength { expected: 32, got: 0 })]
+    #[case::non_hex("d41d8cd98f00b204e9800998ecf8427g", ChecksumEntryStatus::NonHex { position: 31 })]
+    #[case::lowercase_skip("skip", ChecksumEntryStatus::NonHex { position: 0 })]
+    fn classify_md5_checksum_entry(#[case] value: &str, #[case] status: ChecksumEntryStatus) {
+        assert_eq!(
+            classify_checksum_entry(ChecksumAlgorithm::Md5, value),
+            status
+        );
+    }
+
+    #[rstest]
+    #[case(ChecksumAlgorithm::Blake2b512, 128)]
+    #[case(ChecksumAlgorithm::Md5, 32)]
+    #[case(ChecksumAlgorithm::Sha1, 40)]
+    #[case(ChecksumAlgorithm::Sha224, 56)]
+    #[case(ChecksumAlgorithm::Sha256, 64)]
+    #[case(ChecksumAlgorithm::Sha384, 96)]
+    #[case(ChecksumAlgorithm::Sha512, 128)]
+    fn checksum_algorithm_hex_length(#[case] algorithm: ChecksumAlgorithm, #[case] length: usize) {
+        assert_eq!(algorithm.hex_length(), length);
+    }
+
+    /// Ensure that checksums calculated from a file match those calculated from a buffer.
+    #[test]
+    fn checksum_from_file() -> TestResult {
+        // Use more data than fits into a single chunk.
+        let data = (0..200_000).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        let mut file = tempfile::NamedTempFile::new()?;
+        file.write_all(&data)?;
+        let path = file.path();
+
+        assert_eq!(
+            Blake2b512Checksum::from_file(path)?,
+            Blake2b512Checksum::calculate_from(&data)
+        );
+        assert_eq!(
+            Md5Checksum::from_file(path)?,
+            Md5Checksum::calculate_from(&data)
+        );
+        assert_eq!(
+            Sha1Checksum::from_file(path)?,
+            Sha1Checksum::calculate_from(&data)
+        );
+        assert_eq!(
+            Sha224Checksum::from_file(path)?,
+            Sha224Checksum::calculate_from(&data)
+        );
+        assert_eq!(
+            Sha256Checksum::from_file(path)?,
+            Sha256Checksum::calculate_from(&data)
+        );
+        assert_eq!(
+            Sha384Checksum::from_file(path)?,
+            Sha384Checksum::calculate_from(&data)
+        );
+        assert_eq!(
+            Sha512Checksum::from_file(path)?,
+            Sha512Checksum::calculate_from(&data)
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::skip("SKIP", b"foo\n", Ok(()))]
+    #[case::matching(
+        "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c",
+        b"foo\n",
+        Ok(())
+    )]
+    #[case::mismatch(
+        "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c",
+        b"bar\n",
+        Err(Error::ChecksumMismatch {
+            expected: "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c".to_string(),
+            actual: "7d865e959b2466918c9863afca942d0fb89d7c9ac0c99bafc3749504ded97730".to_string(),
+        })
+    )]
+    fn skippable_checksum_verify(
+        #[case] checksum: &str,
+        #[case] data: &[u8],
+        #[case] expected: Result<(), Error>,
+    ) -> TestResult {
+        let checksum = SkippableChecksum::<Sha256>::from_str(checksum)?;
+        assert_eq!(checksum.verify(data), expected);
+        assert_eq!(checksum.verify_reader(data), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn checksum_serde_roundtrip() -> TestResult {
+        let digest = "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c";
+        let checksum = Sha256Checksum::from_str(digest)?;
+        let json = serde_json::to_string(&checksum)?;
+        assert_eq!(json, format!("\"{digest}\""));
+        assert_eq!(serde_json::from_str::<Sha256Checksum>(&json)?, checksum);
+        assert!(serde_json::from_str::<Sha256Checksum>("\"deadbeef\"").is_err());
+
+        for checksum in [
+            SkippableChecksum::<Sha256>::Skip,
+            SkippableChecksum::<Sha256>::from_str(digest)?,
+        ] {
+            let json = serde_json::to_string(&checksum)?;
+            assert_eq!(
+                serde_json::from_str::<SkippableChecksum<Sha256>>(&json)?,
+                checksum
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn sha256_checksums_to_srcinfo() -> TestResult {
+        let checksums = [
+            SkippableChecksum::<Sha256>::from_str(
+                "b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c",
+            )?,
+            SkippableChecksum::Skip,
+            SkippableChecksum::<Sha256>::from_str(
+                "7d865e959b2466918c9863afca942d0fb89d7c9ac0c99bafc3749504ded97730",
+            )?,
+        ];
+
+        assert_eq!(
+            checksums_to_srcinfo(ChecksumAlgorithm::Sha256, &checksums),
+            "\tsha256sums = b5bb9d8014a0f9b1d61e21e796d78dccdf1352f23cd32812f4850b878ae4944c
+\tsha256sums = SKIP
+\tsha256sums = 7d865e959b2466918c9863afca942d0fb89d7c9ac0c99bafc3749504ded97730
+"
+        );
+        assert_eq!(
+            checksums_to_srcinfo::<Sha256>(ChecksumAlgorithm::Sha256, &[]),
+            ""
+        );
+        Ok(())
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(1000))]
 
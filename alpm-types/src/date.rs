@@ -1,8 +1,15 @@
-use time::OffsetDateTime;
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::Error;
 
 /// A build date in seconds since the epoch
 ///
 /// This is a type alias for [`i64`].
+/// As such, a [`BuildDate`] is parsed using the [`FromStr`](std::str::FromStr) implementation of
+/// [`i64`], which only accepts an integer number of seconds and rejects fractional seconds
+/// (e.g. `1700000000.5`) as well as non-numeric input.
+/// Further, [`BuildDate`]s are ordered by the number of seconds since the epoch, which means that
+/// e.g. the most recent one of a set of build dates can be found using [`Iterator::max`].
 ///
 /// # Examples
 /// ```
@@ -18,6 +25,11 @@ use time::OffsetDateTime;
 /// // create BuildDate from &str
 /// assert_eq!(BuildDate::from_str("1"), Ok(1));
 /// assert!(BuildDate::from_str("foo").is_err());
+/// assert!(BuildDate::from_str("1700000000.5").is_err());
+///
+/// // find the most recent BuildDate
+/// let dates: [BuildDate; 3] = [1729181726, 1729181880, 1729181800];
+/// assert_eq!(dates.into_iter().max(), Some(1729181880));
 /// ```
 pub type BuildDate = i64;
 
@@ -36,8 +48,94 @@ impl FromOffsetDateTime for BuildDate {
     }
 }
 
+/// A trait for converting a type from and to an [RFC 3339] timestamp.
+///
+/// [RFC 3339]: https://www.rfc-editor.org/rfc/rfc3339
+pub trait Rfc3339Timestamp: Sized {
+    /// Creates the type from an RFC 3339 timestamp.
+    fn from_rfc3339(input: &str) -> Result<Self, Error>;
+
+    /// Returns the type as an RFC 3339 timestamp.
+    fn to_rfc3339(&self) -> Result<String, Error>;
+}
+
+impl Rfc3339Timestamp for BuildDate {
+    /// Creates a [`BuildDate`] from an RFC 3339 timestamp (e.g. `2024-10-17T16:15:26+02:00`).
+    ///
+    /// The timezone offset of the timestamp is taken into account.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `input` is not a valid RFC 3339 timestamp or if it is before the epoch.
+    ///
+    /// # Examples
+    /// ```
+    /// use alpm_types::{BuildDate, Rfc3339Timestamp};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(BuildDate::from_rfc3339("2024-10-17T16:15:26Z")?, 1729181726);
+    /// assert_eq!(
+    ///     BuildDate::from_rfc3339("2024-10-17T18:15:26+02:00")?,
+    ///     1729181726
+    /// );
+    /// assert!(BuildDate::from_rfc3339("1969-12-31T23:59:59Z").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn from_rfc3339(input: &str) -> Result<Self, Error> {
+        let datetime =
+            OffsetDateTime::parse(input, &Rfc3339).map_err(|error| Error::InvalidBuildDate {
+                input: input.to_string(),
+                reason: error.to_string(),
+            })?;
+        let build_date = BuildDate::from_offset_datetime(datetime);
+        if build_date.is_negative() {
+            return Err(Error::InvalidBuildDate {
+                input: input.to_string(),
+                reason: "it is before the epoch".to_string(),
+            });
+        }
+
+        Ok(build_date)
+    }
+
+    /// Returns the [`BuildDate`] as an RFC 3339 timestamp in UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the [`BuildDate`] is negative or too large to be represented as a date.
+    ///
+    /// # Examples
+    /// ```
+    /// use alpm_types::{BuildDate, Rfc3339Timestamp};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let build_date: BuildDate = 1729181726;
+    /// assert_eq!(build_date.to_rfc3339()?, "2024-10-17T16:15:26Z");
+    /// assert!((-1 as BuildDate).to_rfc3339().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn to_rfc3339(&self) -> Result<String, Error> {
+        let error = |reason: String| Error::InvalidBuildDate {
+            input: self.to_string(),
+            reason,
+        };
+        if self.is_negative() {
+            return Err(error("it is before the epoch".to_string()));
+        }
+
+        OffsetDateTime::from_unix_timestamp(*self)
+            .map_err(|source| error(source.to_string()))?
+            .format(&Rfc3339)
+            .map_err(|source| error(source.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::{num::IntErrorKind, str::FromStr};
+
     use rstest::rstest;
 
     use super::*;
@@ -49,4 +147,71 @@ mod tests {
         let datetime: BuildDate = BuildDate::from_offset_datetime(offset_datetime);
         assert_eq!(builddate, datetime);
     }
+
+    #[rstest]
+    fn builddate_ordering() {
+        let oldest: BuildDate = BuildDate::from_offset_datetime(
+            OffsetDateTime::from_unix_timestamp(1729181726).unwrap(),
+        );
+        let middle: BuildDate = 1729181800;
+        let newest: BuildDate = 1729181880;
+
+        let mut dates = vec![newest, oldest, middle];
+        dates.sort();
+        assert_eq!(dates, vec![oldest, middle, newest]);
+        assert_eq!(dates.iter().max(), Some(&newest));
+        assert_eq!(dates.iter().min(), Some(&oldest));
+        assert_eq!(oldest.max(newest), newest);
+    }
+
+    #[rstest]
+    #[case::utc("2024-10-17T16:15:26Z", 1729181726, "2024-10-17T16:15:26Z")]
+    #[case::epoch("1970-01-01T00:00:00Z", 0, "1970-01-01T00:00:00Z")]
+    #[case::positive_offset("2024-10-17T18:15:26+02:00", 1729181726, "2024-10-17T16:15:26Z")]
+    #[case::negative_offset("2024-10-17T11:45:26-04:30", 1729181726, "2024-10-17T16:15:26Z")]
+    fn builddate_rfc3339_roundtrip(
+        #[case] input: &str,
+        #[case] build_date: BuildDate,
+        #[case] utc: &str,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(BuildDate::from_rfc3339(input)?, build_date);
+        assert_eq!(build_date.to_rfc3339()?, utc);
+        assert_eq!(BuildDate::from_rfc3339(utc)?, build_date);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::before_epoch("1969-12-31T23:59:59Z")]
+    #[case::before_epoch_by_offset("1970-01-01T00:30:00+01:00")]
+    #[case::epoch_seconds("1729181726")]
+    #[case::missing_offset("2024-10-17T16:15:26")]
+    fn builddate_from_rfc3339_fail(#[case] input: &str) {
+        assert!(matches!(
+            BuildDate::from_rfc3339(input),
+            Err(Error::InvalidBuildDate { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case::negative(-1)]
+    #[case::too_large(BuildDate::MAX)]
+    fn builddate_to_rfc3339_fail(#[case] build_date: BuildDate) {
+        assert!(matches!(
+            build_date.to_rfc3339(),
+            Err(Error::InvalidBuildDate { .. })
+        ));
+    }
+
+    #[rstest]
+    #[case("1700000000", Ok(1700000000))]
+    #[case("0", Ok(0))]
+    #[case("1700000000.5", Err(IntErrorKind::InvalidDigit))]
+    #[case("foo", Err(IntErrorKind::InvalidDigit))]
+    #[case("", Err(IntErrorKind::Empty))]
+    fn builddate_from_str(#[case] input: &str, #[case] expected: Result<BuildDate, IntErrorKind>) {
+        assert_eq!(
+            BuildDate::from_str(input).map_err(|error| *error.kind()),
+            expected
+        );
+    }
 }
@@ -6,7 +6,7 @@ use std::{
 
 use serde::Serialize;
 
-use crate::{Architecture, Name, Version, error::Error};
+use crate::{Architecture, Name, PackageRelease, Version, error::Error, parse::validate_charset};
 
 /// An option string
 ///
@@ -33,7 +33,7 @@ use crate::{Architecture, Name, Version, error::Error};
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
 pub struct MakepkgOption {
     name: String,
     on: bool,
@@ -54,8 +54,112 @@ impl MakepkgOption {
     pub fn on(&self) -> bool {
         self.on
     }
+
+    /// Get the lowercase name of the MakepkgOption
+    ///
+    /// ## Examples
+    /// ```
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// use alpm_types::MakepkgOption;
+    ///
+    /// assert_eq!(MakepkgOption::new("!LTO")?.normalized_name(), "lto");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn normalized_name(&self) -> String {
+        self.name.to_lowercase()
+    }
+
+    /// Get the known option that the name of the MakepkgOption matches, ignoring case
+    ///
+    /// The known options are those of the `BUILDENV` and `OPTIONS` arrays documented in
+    /// [makepkg.conf](https://man.archlinux.org/man/makepkg.conf.5.en).
+    /// As option names are case-sensitive, this allows to suggest the canonical option name for
+    /// a misspelled one. Returns [`None`] if the name does not match any known option.
+    ///
+    /// ## Examples
+    /// ```
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// use alpm_types::MakepkgOption;
+    ///
+    /// assert_eq!(
+    ///     MakepkgOption::new("LTO")?.matches_known_ignoring_case(),
+    ///     Some("lto")
+    /// );
+    /// assert_eq!(
+    ///     MakepkgOption::new("!Strip")?.matches_known_ignoring_case(),
+    ///     Some("strip")
+    /// );
+    /// assert_eq!(
+    ///     MakepkgOption::new("foo")?.matches_known_ignoring_case(),
+    ///     None
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches_known_ignoring_case(&self) -> Option<&'static str> {
+        KNOWN_MAKEPKG_OPTIONS
+            .iter()
+            .find(|known| known.eq_ignore_ascii_case(&self.name))
+            .copied()
+    }
+
+    /// Merge two lists of MakepkgOptions, with later options overriding earlier ones
+    ///
+    /// Options are deduplicated by their name, keeping the state of the last occurrence of each
+    /// option in `base` followed by `overrides`.
+    /// The options are returned in the order in which their names have first been seen.
+    /// This mirrors the layering of options in makepkg (e.g. the `options` array of a PKGBUILD
+    /// overriding the `OPTIONS` array of makepkg.conf).
+    ///
+    /// ## Examples
+    /// ```
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// use alpm_types::MakepkgOption;
+    ///
+    /// let base = [MakepkgOption::new("strip")?, MakepkgOption::new("!debug")?];
+    /// let overrides = [MakepkgOption::new("!strip")?];
+    /// assert_eq!(
+    ///     MakepkgOption::merge(&base, &overrides),
+    ///     vec![MakepkgOption::new("!strip")?, MakepkgOption::new("!debug")?]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn merge(base: &[MakepkgOption], overrides: &[MakepkgOption]) -> Vec<MakepkgOption> {
+        let mut merged: Vec<MakepkgOption> = Vec::new();
+        for option in base.iter().chain(overrides) {
+            match merged.iter_mut().find(|other| other.name == option.name) {
+                Some(other) => other.on = option.on,
+                None => merged.push(option.clone()),
+            }
+        }
+
+        merged
+    }
 }
 
+/// The names of the options known to makepkg
+///
+/// This covers the options of the `BUILDENV` and `OPTIONS` arrays of makepkg.conf.
+const KNOWN_MAKEPKG_OPTIONS: &[&str] = &[
+    "autodeps",
+    "ccache",
+    "check",
+    "color",
+    "debug",
+    "distcc",
+    "docs",
+    "emptydirs",
+    "libtool",
+    "lto",
+    "purge",
+    "sign",
+    "staticlibs",
+    "strip",
+    "zipman",
+];
+
 impl FromStr for MakepkgOption {
     type Err = Error;
     /// Create an Option from a string
@@ -65,12 +169,9 @@ impl FromStr for MakepkgOption {
         } else {
             (s.to_owned(), true)
         };
-        if let Some(c) = name
-            .chars()
-            .find(|c| !(c.is_alphanumeric() || ['-', '.', '_'].contains(c)))
-        {
-            return Err(Error::ValueContainsInvalidChars { invalid_char: c });
-        }
+        validate_charset(&name, |c| {
+            c.is_alphanumeric() || ['-', '.', '_'].contains(&c)
+        })?;
         Ok(MakepkgOption { name, on })
     }
 }
@@ -81,6 +182,44 @@ impl Display for MakepkgOption {
     }
 }
 
+/// Validates that a list of [`MakepkgOption`]s does not contain contradictory entries
+///
+/// A list of options (e.g. the `options` array of a PKGBUILD) is contradictory if it contains
+/// an option both in its on and off state (e.g. `lto` and `!lto`).
+/// Repeating an option in the same state is not considered contradictory.
+///
+/// ## Errors
+///
+/// Returns an [`Error::ContradictoryOption`] for the first option that is found in both states.
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{MakepkgOption, validate_options};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let options = [MakepkgOption::new("strip")?, MakepkgOption::new("!docs")?];
+/// assert!(validate_options(&options).is_ok());
+///
+/// let options = [MakepkgOption::new("lto")?, MakepkgOption::new("!lto")?];
+/// assert!(validate_options(&options).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn validate_options(options: &[MakepkgOption]) -> Result<(), Error> {
+    for (index, option) in options.iter().enumerate() {
+        if options[..index]
+            .iter()
+            .any(|other| other.name == option.name && other.on != option.on)
+        {
+            return Err(Error::ContradictoryOption {
+                name: option.name.clone(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 /// An option string used in a build environment
 ///
 /// The option string is identified by its name and whether it is on (not prefixed with "!") or off
@@ -125,11 +264,22 @@ pub type BuildEnvironmentOption = MakepkgOption;
 /// ```
 pub type PackageOption = MakepkgOption;
 
+/// A deprecated alias for [`MakepkgOption`]
+#[deprecated(since = "0.5.0", note = "Use `MakepkgOption` instead")]
+pub type MakePkgOption = MakepkgOption;
+
+/// A deprecated alias for [`BuildEnvironmentOption`]
+#[deprecated(since = "0.5.0", note = "Use `BuildEnvironmentOption` instead")]
+pub type BuildEnv = BuildEnvironmentOption;
+
 /// Information on an installed package in an environment
 ///
 /// Tracks a `Name`, `Version` (which is guaranteed to have a `PackageRelease`) and `Architecture`
 /// of a package in an environment.
 ///
+/// InstalledPackages are ordered by `Name` first, `Version` second (using the vercmp based
+/// ordering of [`Version`]) and `Architecture` last.
+///
 /// ## Examples
 /// ```
 /// use std::str::FromStr;
@@ -150,18 +300,146 @@ pub struct InstalledPackage {
 
 impl InstalledPackage {
     /// Create a new InstalledPackage
+    ///
+    /// This allows to create an InstalledPackage from typed components, without having to
+    /// format and parse a string.
+    /// Any [`Architecture`] (including [`Architecture::Any`]) is valid for an InstalledPackage.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `version` does not have a [`PackageRelease`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{Architecture, InstalledPackage, Name, Version};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let installed = InstalledPackage::new(
+    ///     Name::new("foo")?,
+    ///     Version::from_str("1:1.0.0-1")?,
+    ///     Architecture::Any,
+    /// )?;
+    /// assert_eq!(installed, InstalledPackage::from_str("foo-1:1.0.0-1-any")?);
+    ///
+    /// assert!(
+    ///     InstalledPackage::new(
+    ///         Name::new("foo")?,
+    ///         Version::from_str("1:1.0.0")?,
+    ///         Architecture::Any,
+    ///     )
+    ///     .is_err()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn new(name: Name, version: Version, architecture: Architecture) -> Result<Self, Error> {
+        if version.pkgrel.is_none() {
+            return Err(Error::MissingComponent {
+                component: "pkgrel",
+            });
+        }
+
         Ok(InstalledPackage {
             name,
             version,
             architecture,
         })
     }
+
+    /// Returns the architecture of the InstalledPackage
+    pub fn architecture(&self) -> Architecture {
+        self.architecture
+    }
+
+    /// Returns the file name of the package file of the InstalledPackage
+    ///
+    /// The file name consists of the string representation of the InstalledPackage (i.e.
+    /// `name-[epoch:]pkgver-pkgrel-architecture`), followed by `.pkg.tar` and the compression
+    /// extension `ext` (e.g. `zst`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::InstalledPackage;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let installed = InstalledPackage::from_str("foo-bar-1:1.0.0-1-any")?;
+    /// assert_eq!(
+    ///     installed.to_filename("zst"),
+    ///     "foo-bar-1:1.0.0-1-any.pkg.tar.zst"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_filename(&self, ext: &str) -> String {
+        format!("{self}.pkg.tar.{ext}")
+    }
+}
+
+/// Returns the architecture shared by a set of [`InstalledPackage`]s
+///
+/// [`Architecture::Any`] is treated as a wildcard that is compatible with any other architecture.
+/// If all packages use [`Architecture::Any`], it is returned.
+/// Returns [`None`] if `installed` is empty or if its packages use more than one architecture
+/// other than [`Architecture::Any`] (e.g. a build environment that mixes architectures).
+///
+/// ## Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{Architecture, InstalledPackage, dominant_architecture};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let installed = [
+///     InstalledPackage::from_str("foo-1.0.0-1-x86_64")?,
+///     InstalledPackage::from_str("bar-1.0.0-1-any")?,
+/// ];
+/// assert_eq!(
+///     dominant_architecture(&installed),
+///     Some(Architecture::X86_64)
+/// );
+///
+/// let installed = [
+///     InstalledPackage::from_str("foo-1.0.0-1-x86_64")?,
+///     InstalledPackage::from_str("bar-1.0.0-1-aarch64")?,
+/// ];
+/// assert_eq!(dominant_architecture(&installed), None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn dominant_architecture(installed: &[InstalledPackage]) -> Option<Architecture> {
+    let mut architectures = installed.iter().map(InstalledPackage::architecture);
+    let mut dominant = architectures.next()?;
+
+    for architecture in architectures {
+        match (dominant, architecture) {
+            (_, Architecture::Any) => {}
+            (Architecture::Any, _) => dominant = architecture,
+            (dominant, architecture) if dominant == architecture => {}
+            _ => return None,
+        }
+    }
+
+    Some(dominant)
 }
 
 impl FromStr for InstalledPackage {
     type Err = Error;
     /// Create an Installed from a string
+    ///
+    /// The string is expected to be in the format `name-[epoch:]pkgver-pkgrel-architecture`.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the last component is not a valid [`Architecture`]
+    ///   ([`Error::InvalidInstalledPackageArchitecture`]),
+    /// - the version does not contain a valid pkgrel ([`Error::InstalledPackageMissingPkgrel`]),
+    /// - or the name or version are otherwise invalid.
     fn from_str(s: &str) -> Result<InstalledPackage, Self::Err> {
         const DELIMITER: char = '-';
         let mut parts = s.rsplitn(4, DELIMITER);
@@ -169,12 +447,15 @@ impl FromStr for InstalledPackage {
         let architecture = parts.next().ok_or(Error::MissingComponent {
             component: "architecture",
         })?;
-        let architecture = architecture.parse()?;
+        let architecture = architecture
+            .parse()
+            .map_err(|_| Error::InvalidInstalledPackageArchitecture(architecture.to_string()))?;
         let version = {
-            let Some(pkgrel) = parts.next() else {
-                return Err(Error::MissingComponent {
-                    component: "pkgrel",
-                })?;
+            let Some(pkgrel) = parts
+                .next()
+                .filter(|pkgrel| PackageRelease::from_str(pkgrel).is_ok())
+            else {
+                return Err(Error::InstalledPackageMissingPkgrel(s.to_string()));
             };
             let Some(epoch_pkgver) = parts.next() else {
                 return Err(Error::MissingComponent {
@@ -204,10 +485,46 @@ impl Display for InstalledPackage {
 
 #[cfg(test)]
 mod tests {
+    use std::cmp::Ordering;
+
     use rstest::rstest;
 
     use super::*;
 
+    #[rstest]
+    #[case(&["strip", "!debug"], &["!strip"], &["!strip", "!debug"])]
+    #[case(&["strip", "!debug"], &[], &["strip", "!debug"])]
+    #[case(&[], &["!strip", "lto"], &["!strip", "lto"])]
+    #[case(&["strip", "docs", "lto"], &["!lto", "debug", "!strip"], &["!strip", "docs", "!lto", "debug"])]
+    #[case(&["lto", "!lto"], &["strip", "!strip", "strip"], &["!lto", "strip"])]
+    #[case(&["LTO"], &["!lto"], &["LTO", "!lto"])]
+    fn makepkg_option_merge(
+        #[case] base: &[&str],
+        #[case] overrides: &[&str],
+        #[case] expected: &[&str],
+    ) -> testresult::TestResult<()> {
+        let parse = |options: &[&str]| {
+            options
+                .iter()
+                .map(|option| MakepkgOption::new(option))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        assert_eq!(
+            MakepkgOption::merge(&parse(base)?, &parse(overrides)?),
+            parse(expected)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_option_aliases() -> testresult::TestResult<()> {
+        let option: MakePkgOption = MakepkgOption::new("!lto")?;
+        let env: BuildEnv = BuildEnvironmentOption::new("!lto")?;
+        assert_eq!(option, env);
+        Ok(())
+    }
+
     #[rstest]
     #[case("something", Ok(MakepkgOption{name: "something".to_string(), on: true}))]
     #[case("1cool.build-option", Ok(MakepkgOption{name: "1cool.build-option".to_string(), on: true}))]
@@ -220,6 +537,44 @@ mod tests {
         assert_eq!(MakepkgOption::from_str(s), result);
     }
 
+    #[rstest]
+    #[case("lto", "lto", Some("lto"))]
+    #[case("LTO", "lto", Some("lto"))]
+    #[case("!LTO", "lto", Some("lto"))]
+    #[case("!StaticLibs", "staticlibs", Some("staticlibs"))]
+    #[case("Foo", "foo", None)]
+    fn makepkgoption_known_ignoring_case(
+        #[case] option: &str,
+        #[case] normalized_name: &str,
+        #[case] known: Option<&str>,
+    ) -> testresult::TestResult<()> {
+        let option = MakepkgOption::new(option)?;
+        assert_eq!(option.normalized_name(), normalized_name);
+        assert_eq!(option.matches_known_ignoring_case(), known);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&["strip", "!docs"], Ok(()))]
+    #[case(&["lto", "lto", "!debug"], Ok(()))]
+    #[case(&[], Ok(()))]
+    #[case(&["lto", "!lto"], Err(Error::ContradictoryOption { name: "lto".to_string() }))]
+    #[case(
+        &["!strip", "docs", "strip", "!docs"],
+        Err(Error::ContradictoryOption { name: "strip".to_string() }),
+    )]
+    fn contradictory_options(
+        #[case] options: &[&str],
+        #[case] result: Result<(), Error>,
+    ) -> testresult::TestResult<()> {
+        let options = options
+            .iter()
+            .map(|option| MakepkgOption::new(option))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(validate_options(&options), result);
+        Ok(())
+    }
+
     #[rstest]
     #[case(
         "foo-bar-1:1.0.0-1-any",
@@ -229,10 +584,95 @@ mod tests {
             architecture: Architecture::Any,
         }),
     )]
-    #[case("foo-bar-1:1.0.0-1", Err(strum::ParseError::VariantNotFound.into()))]
-    #[case("foo-bar-1:1.0.0-any", Err(Error::InvalidInteger{ kind: std::num::IntErrorKind::InvalidDigit}))]
+    #[case(
+        "foo-bar-1:1.0.0-1",
+        Err(Error::InvalidInstalledPackageArchitecture("1".to_string())),
+    )]
+    #[case("foo-bar-1:1.0.0-foo", Err(Error::InvalidInstalledPackageArchitecture("foo".to_string())))]
+    #[case("", Err(Error::InvalidInstalledPackageArchitecture("".to_string())))]
+    #[case(
+        "foo-bar-1:1.0.0-any",
+        Err(Error::InstalledPackageMissingPkgrel("foo-bar-1:1.0.0-any".to_string())),
+    )]
+    #[case("foo-any", Err(Error::InstalledPackageMissingPkgrel("foo-any".to_string())))]
+    #[case("any", Err(Error::InstalledPackageMissingPkgrel("any".to_string())))]
+    #[case("1-any", Err(Error::MissingComponent { component: "epoch_pkgver" }))]
     #[case("1:1.0.0-1-any", Err(Error::MissingComponent { component: "name" }))]
     fn installed_new(#[case] s: &str, #[case] result: Result<InstalledPackage, Error>) {
         assert_eq!(InstalledPackage::from_str(s), result);
     }
+
+    #[rstest]
+    #[case(&["foo-1.0.0-1-x86_64", "bar-1.0.0-1-any", "baz-1.0.0-1-x86_64"], Some(Architecture::X86_64))]
+    #[case(&["foo-1.0.0-1-any", "bar-1.0.0-1-x86_64"], Some(Architecture::X86_64))]
+    #[case(&["foo-1.0.0-1-any", "bar-1.0.0-1-any"], Some(Architecture::Any))]
+    #[case(&["foo-1.0.0-1-x86_64", "bar-1.0.0-1-any", "baz-1.0.0-1-aarch64"], None)]
+    #[case(&[], None)]
+    fn installed_dominant_architecture(
+        #[case] installed: &[&str],
+        #[case] architecture: Option<Architecture>,
+    ) -> testresult::TestResult<()> {
+        let installed = installed
+            .iter()
+            .map(|installed| InstalledPackage::from_str(installed))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(dominant_architecture(&installed), architecture);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("foo-1.10.0-1-any", "foo-1.9.0-1-any", Ordering::Greater)]
+    #[case("foo-1.0.0-2-any", "foo-1.0.0-10-any", Ordering::Less)]
+    #[case("foo-1:1.0.0-1-any", "foo-2.0.0-1-any", Ordering::Greater)]
+    #[case("foo-1.0.0-1-any", "foo-1.0-1-any", Ordering::Greater)]
+    #[case("foo-1.0.0-1-x86_64", "foo-1.0.0-1-any", Ordering::Greater)]
+    #[case("bar-2.0.0-1-any", "foo-1.0.0-1-any", Ordering::Less)]
+    #[case("foo-1.0.0-1-any", "foo-1.0.0-1-any", Ordering::Equal)]
+    fn installed_cmp(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] ordering: Ordering,
+    ) -> testresult::TestResult<()> {
+        let a = InstalledPackage::from_str(a)?;
+        let b = InstalledPackage::from_str(b)?;
+        assert_eq!(a.cmp(&b), ordering);
+        assert_eq!(b.cmp(&a), ordering.reverse());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("foo-bar-1:1.0.0-1-any", "zst", "foo-bar-1:1.0.0-1-any.pkg.tar.zst")]
+    #[case("foo-1.0.0-1.1-x86_64", "xz", "foo-1.0.0-1.1-x86_64.pkg.tar.xz")]
+    fn installed_to_filename(
+        #[case] installed: &str,
+        #[case] ext: &str,
+        #[case] filename: &str,
+    ) -> testresult::TestResult<()> {
+        let package = InstalledPackage::from_str(installed)?;
+        assert_eq!(package.to_string(), installed);
+        assert_eq!(package.to_filename(ext), filename);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1:1.0.0-1", Architecture::Any, Ok(()))]
+    #[case("1.0.0-1", Architecture::X86_64, Ok(()))]
+    #[case("1:1.0.0", Architecture::Any, Err(Error::MissingComponent { component: "pkgrel" }))]
+    fn installed_from_components(
+        #[case] version: &str,
+        #[case] architecture: Architecture,
+        #[case] result: Result<(), Error>,
+    ) -> testresult::TestResult<()> {
+        let name = Name::new("foo")?;
+        let version = Version::from_str(version)?;
+        assert_eq!(
+            InstalledPackage::new(name.clone(), version.clone(), architecture),
+            result.map(|_| InstalledPackage {
+                name,
+                version,
+                architecture,
+            })
+        );
+        Ok(())
+    }
 }
@@ -42,6 +42,18 @@ pub enum Error {
     #[error("Invalid semver ({kind})")]
     InvalidSemver { kind: String },
 
+    /// An I/O error
+    #[error("I/O error while {context}: {source}")]
+    IoError {
+        context: &'static str,
+        #[source]
+        source: IoErrorSource,
+    },
+
+    /// A checksum does not match the checksum of the data it is verified against
+    #[error("Checksum mismatch, expected {expected} but got {actual}")]
+    ChecksumMismatch { expected: String, actual: String },
+
     /// Value contains invalid characters
     #[error("Value contains invalid characters: {invalid_char:?}")]
     ValueContainsInvalidChars { invalid_char: char },
@@ -50,6 +62,10 @@ pub enum Error {
     #[error("Incorrect length, got {length} expected {expected}")]
     IncorrectLength { length: usize, expected: usize },
 
+    /// A checksum has a length that does not match any supported algorithm
+    #[error("Unknown checksum length {length}, does not match any supported algorithm")]
+    UnknownChecksumLength { length: usize },
+
     /// Value is missing a delimiter character
     #[error("Value is missing the required delimiter: {delimiter}")]
     DelimiterNotFound { delimiter: char },
@@ -82,6 +98,10 @@ pub enum Error {
     #[error("The path is not relative: {0}")]
     PathNotRelative(PathBuf),
 
+    /// A relative path that escapes its base directory (i.e. using `..`)
+    #[error("The relative path escapes its base directory: {0}")]
+    PathEscapesBase(PathBuf),
+
     /// File name contains invalid characters
     #[error("File name ({0}) contains invalid characters: {1:?}")]
     FileNameContainsInvalidChars(PathBuf, char),
@@ -90,6 +110,10 @@ pub enum Error {
     #[error("File name is empty")]
     FileNameIsEmpty,
 
+    /// Several sources use the same file name
+    #[error("Several sources use the same file name: {name}")]
+    DuplicateSourceFilename { name: PathBuf },
+
     /// A deprecated license
     #[error("Deprecated license: {0}")]
     DeprecatedLicense(String),
@@ -98,21 +122,98 @@ pub enum Error {
     #[error("Invalid OpenPGP v4 fingerprint, only 40 uppercase hexadecimal characters are allowed")]
     InvalidOpenPGPv4Fingerprint,
 
+    /// An invalid OpenPGP v6 fingerprint
+    #[error("Invalid OpenPGP v6 fingerprint, only 64 uppercase hexadecimal characters are allowed")]
+    InvalidOpenPGPv6Fingerprint,
+
+    /// An invalid OpenPGP identifier
+    #[error(
+        "The string is not a valid OpenPGP identifier: {0}, must be 16, 40 or 64 hexadecimal characters"
+    )]
+    InvalidOpenPGPIdentifier(String),
+
     /// An invalid OpenPGP key ID
     #[error("The string is not a valid OpenPGP key ID: {0}, must be 16 hexadecimal characters")]
     InvalidOpenPGPKeyId(String),
 
+    /// An invalid architecture in the string representation of an installed package
+    #[error("Invalid architecture in installed package: {0}")]
+    InvalidInstalledPackageArchitecture(String),
+
+    /// A missing pkgrel in the string representation of an installed package
+    #[error("The version of the installed package is missing a valid pkgrel: {0}")]
+    InstalledPackageMissingPkgrel(String),
+
+    /// An option is both enabled and disabled in the same list of options
+    #[error("The option {name} is both enabled and disabled")]
+    ContradictoryOption { name: String },
+
+    /// An architecture of a package is not an architecture of its package base
+    #[error("The architecture {architecture} is not an architecture of the package base")]
+    ArchitectureNotInPackageBase { architecture: crate::Architecture },
+
+    /// An architecture specific field uses an architecture that is not declared
+    #[error("The field {field} uses the undeclared architecture {architecture}")]
+    UndeclaredArchitecture {
+        field: String,
+        architecture: crate::Architecture,
+    },
+
+    /// An invalid build date
+    #[error("Invalid build date '{input}': {reason}")]
+    InvalidBuildDate { input: String, reason: String },
+
+    /// An invalid human-readable size
+    #[error("Invalid size: {input}")]
+    InvalidSize { input: String },
+
+    /// An invalid package relation
+    #[error("Invalid {part} in package relation: {input}")]
+    InvalidRelation {
+        input: String,
+        part: crate::RelationPart,
+        #[source]
+        source: Option<Box<Error>>,
+    },
+
+    /// An invalid package name
+    #[error("Invalid package name '{name}': {rule}")]
+    InvalidName { name: String, rule: crate::NameRule },
+
     /// An invalid shared object name (v1)
     #[error("Invalid shared object name (v1): {0}")]
     InvalidSonameV1(&'static str),
+
+    /// An extra data entry with an unexpected key
+    #[error("Unexpected extra data key '{key}', expected '{expected}'")]
+    UnexpectedExtraDataKey { key: String, expected: &'static str },
 }
 
 /// Convert a `std::num::ParseIntError` into a `Error::InvalidInteger`
 impl From<std::num::ParseIntError> for crate::error::Error {
     fn from(e: std::num::ParseIntError) -> Self {
-        Self::InvalidInteger {
-            kind: e.kind().clone(),
-        }
+        Self::InvalidInteger { kind: *e.kind() }
+    }
+}
+
+/// An [`std::io::Error`] used as the source of an [`Error::IoError`]
+///
+/// As [`std::io::Error`] does not implement [`PartialEq`], two `IoErrorSource`s are considered
+/// equal if their [`std::io::ErrorKind`] and message are equal.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct IoErrorSource(#[from] std::io::Error);
+
+impl IoErrorSource {
+    /// Return a reference to the inner type
+    pub fn inner(&self) -> &std::io::Error {
+        &self.0
+    }
+}
+
+impl PartialEq for IoErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.kind() == other.0.kind() && self.0.to_string() == other.0.to_string()
     }
 }
 
@@ -132,7 +233,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::{name::NAME_REGEX, openpgp::PACKAGER_REGEX};
+    use crate::openpgp::PACKAGER_REGEX;
 
     #[rstest]
     #[case(
@@ -154,11 +255,10 @@ mod tests {
         }
     )]
     #[case(
-        "Value '€i²' does not match the 'pkgname' regex: ^[a-zA-Z\\d_@+]+[a-zA-Z\\d\\-._@+]*$",
-        Error::RegexDoesNotMatch {
-            value: "€i²".to_string(),
-            regex_type: "pkgname".to_string(),
-            regex: NAME_REGEX.to_string(),
+        "Invalid package name '€i²': contains the disallowed character '€'",
+        Error::InvalidName {
+            name: "€i²".to_string(),
+            rule: crate::NameRule::DisallowedCharacter('€'),
         }
     )]
     #[allow(deprecated)]
@@ -189,4 +289,52 @@ mod tests {
     fn error_format_string(#[case] error_str: &str, #[case] error: Error) {
         assert_eq!(error_str, format!("{}", error));
     }
+
+    #[rstest]
+    #[case("1a", IntErrorKind::InvalidDigit)]
+    #[case("", IntErrorKind::Empty)]
+    #[case("18446744073709551616", IntErrorKind::PosOverflow)]
+    fn error_from_parse_int_error(#[case] input: &str, #[case] kind: IntErrorKind) {
+        let error: Error = input.parse::<u64>().unwrap_err().into();
+        assert_eq!(error, Error::InvalidInteger { kind });
+        assert_eq!(
+            error.to_string(),
+            format!("Invalid integer (caused by {kind:?})")
+        );
+    }
+
+    #[test]
+    fn error_source() {
+        let error: Error = strum::ParseError::VariantNotFound.into();
+        let source = std::error::Error::source(&error)
+            .and_then(|source| source.downcast_ref::<strum::ParseError>());
+        assert_eq!(source, Some(&strum::ParseError::VariantNotFound));
+
+        let error: Error = url::ParseError::EmptyHost.into();
+        let source = std::error::Error::source(&error)
+            .and_then(|source| source.downcast_ref::<url::ParseError>());
+        assert_eq!(source, Some(&url::ParseError::EmptyHost));
+
+        let error = Error::IoError {
+            context: "reading a file",
+            source: std::io::Error::other("disk on fire").into(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "I/O error while reading a file: disk on fire"
+        );
+        let source = std::error::Error::source(&error)
+            .and_then(|source| source.downcast_ref::<IoErrorSource>())
+            .map(IoErrorSource::inner);
+        assert_eq!(
+            source.map(std::io::Error::kind),
+            Some(std::io::ErrorKind::Other)
+        );
+
+        // Errors created from values instead of other errors have no source.
+        let error = Error::MissingComponent {
+            component: "pkgrel",
+        };
+        assert!(std::error::Error::source(&error).is_none());
+    }
 }
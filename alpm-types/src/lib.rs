@@ -4,23 +4,35 @@
 
 mod checksum;
 pub use checksum::{
+    AnyChecksum,
     Blake2b512Checksum,
     Checksum,
+    ChecksumAlgorithm,
+    ChecksumEntryStatus,
+    ChecksumPolicy,
     Digest,
     Md5Checksum,
+    MultiChecksum,
     Sha1Checksum,
     Sha224Checksum,
     Sha256Checksum,
     Sha384Checksum,
     Sha512Checksum,
     SkippableChecksum,
+    checksums_to_srcinfo,
+    classify_checksum_entry,
 };
 
 mod source;
-pub use source::Source;
+pub use source::{
+    Source,
+    missing_checksum_arrays,
+    parse_source_array,
+    validate_unique_source_filenames,
+};
 
 mod url;
-pub use url::{SourceUrl, Url};
+pub use url::{SourceFragment, SourceUrl, Url, VcsProtocol};
 
 /// Public re-exports of common hash functions, for use with [`Checksum`].
 pub mod digests {
@@ -32,19 +44,32 @@ pub mod digests {
 }
 
 mod date;
-pub use date::{BuildDate, FromOffsetDateTime};
+pub use date::{BuildDate, FromOffsetDateTime, Rfc3339Timestamp};
 
 mod env;
-pub use env::{BuildEnvironmentOption, InstalledPackage, MakepkgOption, PackageOption};
+#[allow(deprecated)]
+pub use env::{BuildEnv, MakePkgOption};
+pub use env::{
+    BuildEnvironmentOption,
+    InstalledPackage,
+    MakepkgOption,
+    PackageOption,
+    dominant_architecture,
+    validate_options,
+};
 
 mod error;
-pub use error::Error;
+pub use error::{Error, IoErrorSource};
 
 mod license;
 pub use license::License;
 
 mod name;
-pub use name::{BuildTool, Name, SharedObjectName};
+pub use name::{BuildTool, Name, NameRule, SharedObjectName};
+#[cfg(feature = "interner")]
+pub use name::{InternedName, NameInterner};
+
+mod parse;
 
 mod path;
 pub use path::{
@@ -53,44 +78,85 @@ pub use path::{
     BuildDirectory,
     Changelog,
     Install,
+    InstallHook,
     RelativePath,
     StartDirectory,
+    scan_install_hooks,
 };
 
 mod openpgp;
-pub use openpgp::{OpenPGPIdentifier, OpenPGPKeyId, OpenPGPv4Fingerprint, Packager};
+pub use openpgp::{
+    OpenPGPIdentifier,
+    OpenPGPKeyId,
+    OpenPGPv4Fingerprint,
+    OpenPGPv6Fingerprint,
+    Packager,
+    fingerprint_is_trusted,
+};
 
 mod pkg;
-pub use pkg::{ExtraData, PackageBaseName, PackageDescription, PackageType};
+pub use pkg::{
+    ExtraData,
+    PackageBaseName,
+    PackageDescription,
+    PackageType,
+    debug_package,
+    is_auto_debug_package,
+};
 
 mod relation;
 pub use relation::{
     Group,
+    GroupMembership,
     OptionalDependency,
     PackageRelation,
+    RelationPart,
     SharedLibraryPrefix,
     Soname,
     SonameV1,
     SonameV2,
     VersionOrSoname,
+    is_dependency_satisfied,
+    relation_glob_matches,
+    relation_names,
+    relations_to_block,
+    validate_group,
 };
 
 mod size;
-pub use size::{CompressedSize, InstalledSize};
+pub use size::{
+    CompressedSize,
+    InstalledSize,
+    checked_size_sum,
+    compression_ratio,
+    human_readable_size,
+    size_from_human_readable,
+};
 
 mod system;
-pub use system::{Architecture, ElfArchitectureFormat};
+pub use system::{
+    Architecture,
+    ElfArchitectureFormat,
+    validate_arch_consistency,
+    validate_subpackage_arches,
+};
 
 mod version;
 pub use version::{
     BuildToolVersion,
+    BumpLevel,
+    CmpExplanation,
     Epoch,
     PackageRelease,
     PackageVersion,
     SchemaVersion,
     Version,
+    VersionBuilder,
     VersionComparison,
+    VersionDifference,
     VersionRequirement,
+    vercmp,
+    vercmp_str,
 };
 
 /// Public re-exports for use with [`SchemaVersion`].
@@ -3,10 +3,13 @@ use std::{
     str::FromStr,
 };
 
-use serde::{Serialize, Serializer};
-use spdx::Expression;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use spdx::{
+    Expression,
+    expression::{ExprNode, Operator},
+};
 
-use crate::Error;
+use crate::{Error, parse::deserialize_from_str};
 
 /// Represents a license expression that can be either a valid SPDX identifier
 /// or a non-standard one.
@@ -50,6 +53,16 @@ impl Serialize for License {
     }
 }
 
+impl<'de> Deserialize<'de> for License {
+    /// Custom serde deserialization from the string representation of a [`License`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_from_str(deserializer)
+    }
+}
+
 impl License {
     /// Creates a new license
     ///
@@ -101,6 +114,264 @@ impl License {
     pub fn is_spdx(&self) -> bool {
         matches!(self, License::Spdx(_))
     }
+
+    /// Returns the identifiers of the individual licenses referenced by the license
+    ///
+    /// For an SPDX expression, the identifiers of all referenced licenses are returned in order of
+    /// appearance and as written in the expression, without any `+` suffix or exception (e.g.
+    /// `GPL-2.0-only WITH Classpath-exception-2.0 OR MIT` references `GPL-2.0-only` and `MIT`).
+    /// For a non-standard license, the license itself is returned.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     License::from_str("(GPL-2.0-only WITH Classpath-exception-2.0) OR MIT")?
+    ///         .license_identifiers(),
+    ///     vec!["GPL-2.0-only", "MIT"]
+    /// );
+    /// assert_eq!(
+    ///     License::from_str("My-Custom-License")?.license_identifiers(),
+    ///     vec!["My-Custom-License"]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn license_identifiers(&self) -> Vec<String> {
+        match self {
+            License::Spdx(expression) => expression
+                .requirements()
+                .map(|requirement| {
+                    let span = requirement.span.start as usize..requirement.span.end as usize;
+                    let original: &str = (**expression).as_ref();
+                    original[span].to_string()
+                })
+                .collect(),
+            License::Unknown(license) => vec![license.clone()],
+        }
+    }
+
+    /// Combines the license with another one using the SPDX `AND` operator
+    ///
+    /// Operands are wrapped in parentheses where required to retain their meaning (i.e. if they
+    /// are `OR` expressions, as `AND` binds stronger than `OR`).
+    /// If both licenses are SPDX expressions, the result is an SPDX expression as well.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let license = License::from_str("MIT OR Apache-2.0")?.and(License::from_str("Zlib")?);
+    /// assert!(license.is_spdx());
+    /// assert_eq!(license.to_string(), "(MIT OR Apache-2.0) AND Zlib");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn and(self, other: License) -> License {
+        Self::combine(self, Operator::And, other)
+    }
+
+    /// Combines the license with another one using the SPDX `OR` operator
+    ///
+    /// As `OR` binds weakest, operands are never wrapped in parentheses.
+    /// If both licenses are SPDX expressions, the result is an SPDX expression as well.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let license = License::from_str("MIT")?.or(License::from_str("Apache-2.0")?);
+    /// assert!(license.is_spdx());
+    /// assert_eq!(license.to_string(), "MIT OR Apache-2.0");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn or(self, other: License) -> License {
+        Self::combine(self, Operator::Or, other)
+    }
+
+    /// Combines several licenses using the SPDX `AND` operator
+    ///
+    /// Returns [`None`] if `licenses` is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let licenses = vec![License::from_str("MIT")?, License::from_str("Zlib")?];
+    /// assert_eq!(
+    ///     License::all(licenses).map(|license| license.to_string()),
+    ///     Some("MIT AND Zlib".to_string())
+    /// );
+    /// assert_eq!(License::all(Vec::new()), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn all(licenses: impl IntoIterator<Item = License>) -> Option<License> {
+        licenses.into_iter().reduce(License::and)
+    }
+
+    /// Combines several licenses using the SPDX `OR` operator
+    ///
+    /// Returns [`None`] if `licenses` is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let licenses = vec![License::from_str("MIT")?, License::from_str("Zlib")?];
+    /// assert_eq!(
+    ///     License::any(licenses).map(|license| license.to_string()),
+    ///     Some("MIT OR Zlib".to_string())
+    /// );
+    /// assert_eq!(License::any(Vec::new()), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn any(licenses: impl IntoIterator<Item = License>) -> Option<License> {
+        licenses.into_iter().reduce(License::or)
+    }
+
+    /// Combines two licenses using `operator`
+    fn combine(left: License, operator: Operator, right: License) -> License {
+        let operator_str = match operator {
+            Operator::And => "AND",
+            Operator::Or => "OR",
+        };
+        let combined = format!(
+            "{} {operator_str} {}",
+            left.as_operand_of(operator),
+            right.as_operand_of(operator)
+        );
+
+        match (&left, &right) {
+            (License::Spdx(_), License::Spdx(_)) => {
+                Self::from_valid_spdx(combined.clone()).unwrap_or(License::Unknown(combined))
+            }
+            _ => License::Unknown(combined),
+        }
+    }
+
+    /// Returns the license as string, parenthesized if required to be an operand of `operator`
+    fn as_operand_of(&self, operator: Operator) -> String {
+        let requires_parentheses = match self {
+            // Only `OR` binds weaker than `AND`, which in turn binds weaker than `WITH`.
+            License::Spdx(expression) => {
+                matches!(operator, Operator::And)
+                    && matches!(expression.iter().last(), Some(ExprNode::Op(Operator::Or)))
+            }
+            // The structure of a non-standard license is unknown.
+            License::Unknown(license) => license.contains(char::is_whitespace),
+        };
+
+        if requires_parentheses {
+            format!("({self})")
+        } else {
+            self.to_string()
+        }
+    }
+
+    /// Returns `true` if the license is a sentinel for "no license"
+    ///
+    /// Packages, for which no license applies or for which the license is not known, may use the
+    /// sentinel values `none` or `unknown` (compared case-insensitively) instead of a license
+    /// identifier. These are never valid SPDX identifiers.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert!(License::from_str("none")?.is_none_sentinel());
+    /// assert!(License::from_str("unknown")?.is_none_sentinel());
+    /// assert!(!License::from_str("MIT")?.is_none_sentinel());
+    /// assert!(!License::from_str("custom:none")?.is_none_sentinel());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_none_sentinel(&self) -> bool {
+        match self {
+            License::Spdx(_) => false,
+            License::Unknown(license) => {
+                ["none", "unknown"].contains(&license.to_ascii_lowercase().as_str())
+            }
+        }
+    }
+
+    /// Parses a license field, that may contain several licenses, into a list of [`License`]s
+    ///
+    /// The input is first split into lines.
+    /// Each line, that represents a compound SPDX expression (i.e. it contains parentheses or one
+    /// of the `AND`, `OR` or `WITH` operators), is treated as a single license.
+    /// All other lines are split on commas (`,`) and whitespace, each entry being one license.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use alpm_types::License;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// // A compound SPDX expression is one license
+    /// assert_eq!(License::parse_field("(MIT OR Apache-2.0)")?.len(), 1);
+    ///
+    /// // Separate entries are several licenses
+    /// assert_eq!(License::parse_field("MIT, Apache-2.0")?.len(), 2);
+    /// assert_eq!(
+    ///     License::parse_field("MIT\nApache-2.0 WITH LLVM-exception")?.len(),
+    ///     2
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the licenses can not be created using [`License::from_str`].
+    pub fn parse_field(s: &str) -> Result<Vec<Self>, Error> {
+        let mut licenses = Vec::new();
+        for line in s.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let is_expression = line.contains(['(', ')'])
+                || line
+                    .split_whitespace()
+                    .any(|token| matches!(token, "AND" | "OR" | "WITH"));
+            if is_expression {
+                licenses.push(Self::from_str(line)?);
+            } else {
+                for entry in line
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|entry| !entry.is_empty())
+                {
+                    licenses.push(Self::from_str(entry)?);
+                }
+            }
+        }
+
+        Ok(licenses)
+    }
 }
 
 impl FromStr for License {
@@ -206,6 +477,33 @@ mod tests {
         assert_eq!(result, Err(Error::DeprecatedLicense(input.to_string())));
     }
 
+    #[rstest]
+    #[case("(MIT OR Apache-2.0)", vec!["(MIT OR Apache-2.0)"])]
+    #[case("MIT OR Apache-2.0", vec!["MIT OR Apache-2.0"])]
+    #[case("Apache-2.0 WITH LLVM-exception", vec!["Apache-2.0 WITH LLVM-exception"])]
+    #[case("MIT Apache-2.0", vec!["MIT", "Apache-2.0"])]
+    #[case("MIT, Apache-2.0", vec!["MIT", "Apache-2.0"])]
+    #[case("MIT\nApache-2.0", vec!["MIT", "Apache-2.0"])]
+    #[case(
+        "(MIT OR Apache-2.0)\nGPL-3.0-or-later, Custom-License\n\n",
+        vec!["(MIT OR Apache-2.0)", "GPL-3.0-or-later", "Custom-License"]
+    )]
+    #[case("", vec![])]
+    fn test_parse_field(
+        #[case] input: &str,
+        #[case] expected: Vec<&str>,
+    ) -> testresult::TestResult<()> {
+        let licenses = License::parse_field(input)?;
+        assert_eq!(
+            licenses,
+            expected
+                .into_iter()
+                .map(License::from_str)
+                .collect::<Result<Vec<_>, _>>()?
+        );
+        Ok(())
+    }
+
     #[rstest]
     #[case("MIT", true)]
     #[case("Custom-License", false)]
@@ -215,4 +513,168 @@ mod tests {
 
         Ok(())
     }
+
+    #[rstest]
+    #[case("none", true)]
+    #[case("None", true)]
+    #[case("unknown", true)]
+    #[case("UNKNOWN", true)]
+    #[case("MIT", false)]
+    #[case("NonStandard-License", false)]
+    #[case("custom:none", false)]
+    fn license_is_none_sentinel(
+        #[case] input: &str,
+        #[case] expected: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(License::from_str(input)?.is_none_sentinel(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("MIT")]
+    #[case("GPL-3.0-or-later OR Apache-2.0")]
+    #[case("NonStandard-License")]
+    fn license_serde_roundtrip(#[case] input: &str) -> testresult::TestResult<()> {
+        let license = License::from_str(input)?;
+        let json = serde_json::to_string(&license)?;
+        assert_eq!(serde_json::from_str::<License>(&json)?, license);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("MIT OR Apache-2.0", &["MIT", "Apache-2.0"])]
+    #[case("(GPL-2.0-only WITH Classpath-exception-2.0)", &["GPL-2.0-only"])]
+    #[case("LGPL-3.0-or-later", &["LGPL-3.0-or-later"])]
+    #[case("Apache-2.0+ AND (MIT OR BSD-3-Clause)", &["Apache-2.0", "MIT", "BSD-3-Clause"])]
+    #[case("LicenseRef-Custom", &["LicenseRef-Custom"])]
+    fn valid_spdx_expression(
+        #[case] input: &str,
+        #[case] identifiers: &[&str],
+    ) -> testresult::TestResult<()> {
+        let license = License::from_valid_spdx(input.to_string())?;
+        assert!(license.is_spdx());
+        assert_eq!(license.license_identifiers(), identifiers);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("MIT", "Apache-2.0", "MIT OR Apache-2.0", "MIT AND Apache-2.0")]
+    #[case(
+        "MIT OR Apache-2.0",
+        "Zlib",
+        "MIT OR Apache-2.0 OR Zlib",
+        "(MIT OR Apache-2.0) AND Zlib"
+    )]
+    #[case(
+        "MIT AND Apache-2.0",
+        "Zlib OR ISC",
+        "MIT AND Apache-2.0 OR Zlib OR ISC",
+        "MIT AND Apache-2.0 AND (Zlib OR ISC)"
+    )]
+    #[case(
+        "Apache-2.0 WITH LLVM-exception",
+        "MIT",
+        "Apache-2.0 WITH LLVM-exception OR MIT",
+        "Apache-2.0 WITH LLVM-exception AND MIT"
+    )]
+    fn license_combine(
+        #[case] left: &str,
+        #[case] right: &str,
+        #[case] or: &str,
+        #[case] and: &str,
+    ) -> testresult::TestResult<()> {
+        let left = License::from_str(left)?;
+        let right = License::from_str(right)?;
+
+        let license = left.clone().or(right.clone());
+        assert!(license.is_spdx());
+        assert_eq!(license.to_string(), or);
+        assert_eq!(License::from_valid_spdx(license.to_string())?, license);
+
+        let license = left.and(right);
+        assert!(license.is_spdx());
+        assert_eq!(license.to_string(), and);
+        assert_eq!(License::from_valid_spdx(license.to_string())?, license);
+        Ok(())
+    }
+
+    #[test]
+    fn license_combine_precedence() -> testresult::TestResult<()> {
+        let license = License::from_str("MIT")?
+            .or(License::from_str("Apache-2.0")?)
+            .and(License::from_str("Zlib")?.or(License::from_str("ISC")?));
+        assert_eq!(license.to_string(), "(MIT OR Apache-2.0) AND (Zlib OR ISC)");
+
+        let License::Spdx(expression) = &license else {
+            panic!("The combined license is not an SPDX expression");
+        };
+        // The expression is only satisfied if one license of each operand is accepted.
+        assert!(
+            expression
+                .evaluate(|req| matches!(req.license.id().map(|id| id.name), Some("MIT" | "ISC")))
+        );
+        assert!(!expression.evaluate(|req| matches!(
+            req.license.id().map(|id| id.name),
+            Some("MIT" | "Apache-2.0")
+        )));
+        Ok(())
+    }
+
+    #[test]
+    fn license_combine_unknown() -> testresult::TestResult<()> {
+        let license = License::from_str("MIT")?.and(License::from_str("Custom License")?);
+        assert!(!license.is_spdx());
+        assert_eq!(license.to_string(), "MIT AND (Custom License)");
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[], None, None)]
+    #[case(&["MIT"], Some("MIT"), Some("MIT"))]
+    #[case(
+        &["MIT", "Apache-2.0 OR Zlib", "ISC"],
+        Some("MIT AND (Apache-2.0 OR Zlib) AND ISC"),
+        Some("MIT OR Apache-2.0 OR Zlib OR ISC")
+    )]
+    fn license_all_any(
+        #[case] licenses: &[&str],
+        #[case] all: Option<&str>,
+        #[case] any: Option<&str>,
+    ) -> testresult::TestResult<()> {
+        let licenses = licenses
+            .iter()
+            .map(|license| License::from_str(license))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            License::all(licenses.clone())
+                .map(|license| license.to_string())
+                .as_deref(),
+            all
+        );
+        assert_eq!(
+            License::any(licenses)
+                .map(|license| license.to_string())
+                .as_deref(),
+            any
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("MIT OR")]
+    #[case("(MIT OR Apache-2.0")]
+    #[case("MIT OR Apache-2.0)")]
+    #[case("AND MIT")]
+    fn invalid_spdx_expression(#[case] input: &str) -> testresult::TestResult<()> {
+        assert!(matches!(
+            License::from_valid_spdx(input.to_string()),
+            Err(Error::InvalidLicense(_))
+        ));
+
+        // Non-strict parsing falls back to a non-standard license
+        let license = License::from_str(input)?;
+        assert!(!license.is_spdx());
+        assert_eq!(license.license_identifiers(), vec![input.to_string()]);
+        Ok(())
+    }
 }
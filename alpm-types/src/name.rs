@@ -1,12 +1,12 @@
+#[cfg(feature = "interner")]
+use std::{borrow::Borrow, collections::HashMap, sync::Arc};
 use std::{
     fmt::{Display, Formatter},
     str::FromStr,
     string::ToString,
 };
 
-use lazy_regex::{Lazy, lazy_regex};
-use regex::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 use winnow::{
     ModalResult,
     Parser,
@@ -15,9 +15,7 @@ use winnow::{
     token::any,
 };
 
-use crate::Error;
-
-pub(crate) static NAME_REGEX: Lazy<Regex> = lazy_regex!(r"^[a-zA-Z\d_@+]+[a-zA-Z\d\-._@+]*$");
+use crate::{Error, parse::deserialize_from_str};
 
 /// A build tool name
 ///
@@ -107,8 +105,10 @@ impl Display for BuildTool {
 
 /// A package name
 ///
-/// Package names may contain the characters `[a-z\d\-._@+]`, but must not
-/// start with `[-.]`.
+/// Package names must not be empty and may only contain the ASCII characters `[a-zA-Z0-9\-._@+]`,
+/// but must not start with `[-.+]`.
+/// This means that e.g. `gtk2+` or `gcc++` are valid package names, while `+foo` is not.
+/// If a name is invalid, an [`Error::InvalidName`] with the violated [`NameRule`] is returned.
 ///
 /// ## Examples
 /// ```
@@ -123,13 +123,15 @@ impl Display for BuildTool {
 ///     Ok(Name::new("test-123@.foo_+")?)
 /// );
 /// assert!(Name::from_str(".test").is_err());
+/// assert!(Name::from_str("gcc++").is_ok());
+/// assert!(Name::from_str("+foo").is_err());
 ///
 /// // format as String
 /// assert_eq!("foo", format!("{}", Name::new("foo")?));
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Name(String);
 
 impl Name {
@@ -147,16 +149,33 @@ impl Name {
 impl FromStr for Name {
     type Err = Error;
     /// Create a Name from a string
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::InvalidName`] if
+    ///
+    /// - `s` is empty,
+    /// - `s` starts with `-`, `.` or `+`,
+    /// - or `s` contains a character other than `[a-zA-Z0-9\-._@+]`.
     fn from_str(s: &str) -> Result<Name, Self::Err> {
-        if NAME_REGEX.is_match(s) {
-            Ok(Name(s.to_string()))
-        } else {
-            Err(Error::RegexDoesNotMatch {
-                value: s.to_string(),
-                regex_type: "pkgname".to_string(),
-                regex: NAME_REGEX.to_string(),
-            })
+        let error = |rule| Error::InvalidName {
+            name: s.to_string(),
+            rule,
+        };
+
+        let Some(first) = s.chars().next() else {
+            return Err(error(NameRule::Empty));
+        };
+        if matches!(first, '-' | '.' | '+') {
+            return Err(error(NameRule::LeadingCharacter(first)));
+        }
+        if let Some(invalid) = s.chars().find(|char| {
+            !(char.is_ascii_alphanumeric() || matches!(char, '-' | '.' | '_' | '@' | '+'))
+        }) {
+            return Err(error(NameRule::DisallowedCharacter(invalid)));
         }
+
+        Ok(Name(s.to_string()))
     }
 }
 
@@ -166,12 +185,47 @@ impl Display for Name {
     }
 }
 
+impl<'de> Deserialize<'de> for Name {
+    /// Deserializes a [`Name`] from its string representation.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_from_str(deserializer)
+    }
+}
+
 impl AsRef<str> for Name {
     fn as_ref(&self) -> &str {
         self.inner()
     }
 }
 
+/// A rule for package names, that is violated by an invalid [`Name`]
+///
+/// This is used in [`Error::InvalidName`] to indicate why a package name is invalid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NameRule {
+    /// The name is empty.
+    Empty,
+    /// The name starts with a character that is not allowed as first character (`-`, `.` or `+`).
+    LeadingCharacter(char),
+    /// The name contains a character that is not allowed in package names.
+    DisallowedCharacter(char),
+}
+
+impl Display for NameRule {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match self {
+            NameRule::Empty => write!(fmt, "must not be empty"),
+            NameRule::LeadingCharacter(char) => write!(fmt, "must not start with '{char}'"),
+            NameRule::DisallowedCharacter(char) => {
+                write!(fmt, "contains the disallowed character '{char}'")
+            }
+        }
+    }
+}
+
 /// A shared object name.
 ///
 /// This type wraps a [`Name`] and is used to represent the name of a shared object file
@@ -256,6 +310,99 @@ impl Display for SharedObjectName {
     }
 }
 
+/// A handle to a [`Name`] stored in a [`NameInterner`]
+///
+/// An [`InternedName`] is only meaningful in the context of the [`NameInterner`] that created it.
+#[cfg(feature = "interner")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InternedName(usize);
+
+/// A table of deduplicated [`Name`]s
+///
+/// When handling large amounts of package data, the same names occur many times (e.g. in
+/// package relations). A [`NameInterner`] stores each distinct [`Name`] only once and hands out
+/// cheap [`InternedName`] handles for it, which can be resolved back to the [`Name`].
+///
+/// Each [`Name`] is allocated once and shared between the lookup by string and the lookup by
+/// handle.
+///
+/// ## Examples
+/// ```
+/// use alpm_types::NameInterner;
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let mut interner = NameInterner::default();
+/// let first = interner.intern("example")?;
+/// let second = interner.intern("example")?;
+///
+/// assert_eq!(first, second);
+/// assert_eq!(interner.resolve(first).as_ref(), "example");
+/// assert_eq!(interner.len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "interner")]
+#[derive(Clone, Debug, Default)]
+pub struct NameInterner {
+    names: Vec<Arc<Name>>,
+    handles: HashMap<InternerKey, InternedName>,
+}
+
+/// A [`Name`] shared between the tables of a [`NameInterner`]
+///
+/// The key can be looked up by `&str`, as it hashes and compares like the string of its [`Name`].
+#[cfg(feature = "interner")]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct InternerKey(Arc<Name>);
+
+#[cfg(feature = "interner")]
+impl Borrow<str> for InternerKey {
+    fn borrow(&self) -> &str {
+        self.0.inner()
+    }
+}
+
+#[cfg(feature = "interner")]
+impl NameInterner {
+    /// Interns a name and returns its handle
+    ///
+    /// If the name has been interned before, the existing handle is returned.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `name` is not a valid [`Name`].
+    pub fn intern(&mut self, name: &str) -> Result<InternedName, Error> {
+        if let Some(handle) = self.handles.get(name) {
+            return Ok(*handle);
+        }
+
+        let handle = InternedName(self.names.len());
+        let name = Arc::new(Name::new(name)?);
+        self.names.push(Arc::clone(&name));
+        self.handles.insert(InternerKey(name), handle);
+        Ok(handle)
+    }
+
+    /// Returns the [`Name`] of a handle
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `handle` has not been created by this [`NameInterner`].
+    pub fn resolve(&self, handle: InternedName) -> &Name {
+        &self.names[handle.0]
+    }
+
+    /// Returns the number of distinct names
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if no name has been interned
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use proptest::prelude::*;
@@ -303,32 +450,60 @@ mod tests {
         #![proptest_config(ProptestConfig::with_cases(1000))]
 
         #[test]
-        fn valid_name_from_string(name_str in r"[a-zA-Z\d_@+]+[a-zA-Z\d\-._@+]*") {
+        fn valid_name_from_string(name_str in r"[a-zA-Z0-9_@][a-zA-Z0-9\-._@+]*") {
             let name = Name::from_str(&name_str).unwrap();
             prop_assert_eq!(name_str, format!("{}", name));
         }
 
         #[test]
-        fn invalid_name_from_string_start(name_str in r"[-.][a-zA-Z0-9@._+-]*") {
+        fn invalid_name_from_string_start(name_str in r"[-.+][a-zA-Z0-9@._+-]*") {
             let error = Name::from_str(&name_str).unwrap_err();
-            assert_eq!(error, Error::RegexDoesNotMatch {
-                value: name_str.to_string(),
-                regex_type: "pkgname".to_string(),
-                regex: NAME_REGEX.to_string(),
+            assert_eq!(error, Error::InvalidName {
+                name: name_str.to_string(),
+                rule: NameRule::LeadingCharacter(name_str.chars().next().unwrap()),
             });
         }
 
         #[test]
         fn invalid_name_with_invalid_characters(name_str in r"[^\w@._+-]+") {
             let error = Name::from_str(&name_str).unwrap_err();
-            assert_eq!(error, Error::RegexDoesNotMatch {
-                value: name_str.to_string(),
-                regex_type: "pkgname".to_string(),
-                regex: NAME_REGEX.to_string(),
+            assert_eq!(error, Error::InvalidName {
+                name: name_str.to_string(),
+                rule: NameRule::DisallowedCharacter(name_str.chars().next().unwrap()),
             });
         }
     }
 
+    #[rstest]
+    #[case("gcc++")]
+    #[case("lib+")]
+    #[case("gtk2+")]
+    #[case("gtk3+")]
+    #[case("notify-osd+")]
+    fn name_with_plus(#[case] input: &str) -> testresult::TestResult<()> {
+        let name = Name::new(input)?;
+        assert_eq!(input, name.inner());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::leading_plus("+foo", NameRule::LeadingCharacter('+'))]
+    #[case::only_plus("+", NameRule::LeadingCharacter('+'))]
+    #[case::leading_hyphen("-foo", NameRule::LeadingCharacter('-'))]
+    #[case::leading_dot(".foo", NameRule::LeadingCharacter('.'))]
+    #[case::embedded_space("foo bar", NameRule::DisallowedCharacter(' '))]
+    #[case::non_ascii_digit("foo٣", NameRule::DisallowedCharacter('٣'))]
+    #[case::empty("", NameRule::Empty)]
+    fn invalid_name(#[case] input: &str, #[case] rule: NameRule) {
+        assert_eq!(
+            Name::new(input),
+            Err(Error::InvalidName {
+                name: input.to_string(),
+                rule,
+            })
+        );
+    }
+
     #[rstest]
     #[case("example.so", SharedObjectName("example.so".parse().unwrap()))]
     #[case("example.so.so", SharedObjectName("example.so.so".parse().unwrap()))]
@@ -356,4 +531,42 @@ mod tests {
             "Error:\n=====\n{pretty_error}\n=====\nshould contain snippet:\n\n{error_snippet}"
         );
     }
+
+    #[cfg(feature = "interner")]
+    #[test]
+    fn name_interner() -> testresult::TestResult<()> {
+        let mut interner = NameInterner::default();
+        let example = interner.intern("example")?;
+        let other = interner.intern("other")?;
+
+        assert_eq!(interner.intern("example")?, example);
+        assert_ne!(example, other);
+        assert_eq!(interner.resolve(example), &Name::new("example")?);
+        assert_eq!(interner.resolve(other), &Name::new("other")?);
+        assert_eq!(interner.len(), 2);
+        assert!(interner.intern("-invalid").is_err());
+        assert_eq!(interner.len(), 2);
+
+        // Each name is only allocated once.
+        for (key, handle) in &interner.handles {
+            assert!(Arc::ptr_eq(&key.0, &interner.names[handle.0]));
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("example")]
+    #[case("example-1.0_foo@bar+baz")]
+    fn name_serde_roundtrip(#[case] name: &str) -> testresult::TestResult<()> {
+        let name = Name::new(name)?;
+        let json = serde_json::to_string(&name)?;
+        assert_eq!(json, format!("\"{name}\""));
+        assert_eq!(serde_json::from_str::<Name>(&json)?, name);
+        Ok(())
+    }
+
+    #[test]
+    fn name_deserialize_invalid() {
+        assert!(serde_json::from_str::<Name>("\"-invalid\"").is_err());
+    }
 }
@@ -14,10 +14,12 @@ use crate::Error;
 /// An OpenPGP key identifier.
 ///
 /// The `OpenPGPIdentifier` enum represents a valid OpenPGP identifier, which can be either an
-/// OpenPGP Key ID or an OpenPGP v4 fingerprint.
+/// OpenPGP Key ID, an OpenPGP v4 fingerprint or an OpenPGP v6 fingerprint.
 ///
-/// This type wraps an [`OpenPGPKeyId`] and an [`OpenPGPv4Fingerprint`] and provides a unified
-/// interface for both.
+/// This type wraps an [`OpenPGPKeyId`], an [`OpenPGPv4Fingerprint`] and an
+/// [`OpenPGPv6Fingerprint`] and provides a unified interface for all of them.
+/// When parsing, the variant is chosen based on the length of the input (16 characters for a Key
+/// ID, 40 for a v4 fingerprint and 64 for a v6 fingerprint).
 ///
 /// ## Examples
 ///
@@ -48,6 +50,15 @@ use crate::Error;
 /// );
 /// assert_eq!(key.to_string(), "2F2670AC164DB36F");
 /// assert_eq!(key, OpenPGPKeyId::from_str("2F2670AC164DB36F")?.into());
+///
+/// // Create a OpenPGPIdentifier from a valid OpenPGP v6 fingerprint
+/// let key = OpenPGPIdentifier::from_str(
+///     "CB186C4F0609A697E4D52DFA6C722B0C1F1E27C18A56708F6525EC27BAD9ACC9",
+/// )?;
+/// assert!(matches!(key, OpenPGPIdentifier::OpenPGPv6Fingerprint(_)));
+///
+/// // Identifiers of any other length are invalid
+/// assert!(OpenPGPIdentifier::from_str("2F2670AC").is_err());
 /// # Ok(())
 /// # }
 /// ```
@@ -59,18 +70,121 @@ pub enum OpenPGPIdentifier {
     /// An OpenPGP v4 fingerprint.
     #[serde(rename = "openpgp_v4_fingerprint")]
     OpenPGPv4Fingerprint(OpenPGPv4Fingerprint),
+    /// An OpenPGP v6 fingerprint.
+    #[serde(rename = "openpgp_v6_fingerprint")]
+    OpenPGPv6Fingerprint(OpenPGPv6Fingerprint),
+}
+
+impl OpenPGPIdentifier {
+    /// Parses a whitespace-separated list of [`OpenPGPIdentifier`]s.
+    ///
+    /// This is useful for parsing the contents of a `validpgpkeys` array, in which each entry may
+    /// be an OpenPGP fingerprint or an OpenPGP Key ID.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if any of the entries is not a valid [`OpenPGPIdentifier`].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use alpm_types::OpenPGPIdentifier;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let keys =
+    ///     OpenPGPIdentifier::parse_list("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E 2F2670AC164DB36F")?;
+    /// assert_eq!(keys.len(), 2);
+    /// assert!(OpenPGPIdentifier::parse_list("2F2670AC164DB36F invalid").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, Error> {
+        s.split_whitespace().map(Self::from_str).collect()
+    }
+
+    /// Returns `true` if the identifier refers to the key with the given fingerprint.
+    ///
+    /// An [`OpenPGPv4Fingerprint`] matches if it is equal to `fingerprint`.
+    /// An [`OpenPGPKeyId`] matches if it is equal to the last 16 characters of `fingerprint`
+    /// (i.e. the long key ID of an OpenPGP v4 key).
+    /// An [`OpenPGPv6Fingerprint`] never matches, as it belongs to a different key version.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{OpenPGPIdentifier, OpenPGPv4Fingerprint};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let fingerprint = OpenPGPv4Fingerprint::from_str("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E")?;
+    ///
+    /// assert!(OpenPGPIdentifier::from_str("1C2358A25A10D94E")?.matches(&fingerprint));
+    /// assert!(!OpenPGPIdentifier::from_str("2F2670AC164DB36F")?.matches(&fingerprint));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches(&self, fingerprint: &OpenPGPv4Fingerprint) -> bool {
+        match self {
+            OpenPGPIdentifier::OpenPGPKeyId(key_id) => {
+                fingerprint.as_str().ends_with(key_id.as_str())
+            }
+            OpenPGPIdentifier::OpenPGPv4Fingerprint(trusted) => trusted == fingerprint,
+            OpenPGPIdentifier::OpenPGPv6Fingerprint(_) => false,
+        }
+    }
+}
+
+/// Returns `true` if an [`OpenPGPv4Fingerprint`] matches any of a list of trusted
+/// [`OpenPGPIdentifier`]s.
+///
+/// This can be used to check whether the key that created a signature is part of the
+/// `validpgpkeys` of a package (see [`OpenPGPIdentifier::matches`]).
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{OpenPGPIdentifier, OpenPGPv4Fingerprint, fingerprint_is_trusted};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let fingerprint = OpenPGPv4Fingerprint::from_str("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E")?;
+///
+/// let trusted = OpenPGPIdentifier::parse_list("2F2670AC164DB36F 1C2358A25A10D94E")?;
+/// assert!(fingerprint_is_trusted(&fingerprint, &trusted));
+///
+/// let trusted = OpenPGPIdentifier::parse_list("2F2670AC164DB36F")?;
+/// assert!(!fingerprint_is_trusted(&fingerprint, &trusted));
+/// # Ok(())
+/// # }
+/// ```
+pub fn fingerprint_is_trusted(
+    fingerprint: &OpenPGPv4Fingerprint,
+    trusted: &[OpenPGPIdentifier],
+) -> bool {
+    trusted
+        .iter()
+        .any(|identifier| identifier.matches(fingerprint))
 }
 
 impl FromStr for OpenPGPIdentifier {
     type Err = Error;
 
+    /// Creates an [`OpenPGPIdentifier`] based on the length of `s`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - `s` is not 16, 40 or 64 characters long,
+    /// - or `s` is not a valid identifier of the type matching its length.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s.parse::<OpenPGPv4Fingerprint>() {
-            Ok(fingerprint) => Ok(OpenPGPIdentifier::OpenPGPv4Fingerprint(fingerprint)),
-            Err(_) => match s.parse::<OpenPGPKeyId>() {
-                Ok(key_id) => Ok(OpenPGPIdentifier::OpenPGPKeyId(key_id)),
-                Err(e) => Err(e),
-            },
+        match s.len() {
+            16 => Ok(OpenPGPIdentifier::OpenPGPKeyId(s.parse()?)),
+            40 => Ok(OpenPGPIdentifier::OpenPGPv4Fingerprint(s.parse()?)),
+            64 => Ok(OpenPGPIdentifier::OpenPGPv6Fingerprint(s.parse()?)),
+            _ => Err(Error::InvalidOpenPGPIdentifier(s.to_string())),
         }
     }
 }
@@ -87,11 +201,18 @@ impl From<OpenPGPv4Fingerprint> for OpenPGPIdentifier {
     }
 }
 
+impl From<OpenPGPv6Fingerprint> for OpenPGPIdentifier {
+    fn from(fingerprint: OpenPGPv6Fingerprint) -> Self {
+        OpenPGPIdentifier::OpenPGPv6Fingerprint(fingerprint)
+    }
+}
+
 impl Display for OpenPGPIdentifier {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
             OpenPGPIdentifier::OpenPGPKeyId(key_id) => write!(f, "{key_id}"),
             OpenPGPIdentifier::OpenPGPv4Fingerprint(fingerprint) => write!(f, "{fingerprint}"),
+            OpenPGPIdentifier::OpenPGPv6Fingerprint(fingerprint) => write!(f, "{fingerprint}"),
         }
     }
 }
@@ -266,6 +387,91 @@ impl Display for OpenPGPv4Fingerprint {
     }
 }
 
+/// An OpenPGP v6 fingerprint.
+///
+/// The `OpenPGPv6Fingerprint` type wraps a `String` representing an [OpenPGP v6 fingerprint],
+/// ensuring that it consists of exactly 64 uppercase hexadecimal characters.
+///
+/// [OpenPGP v6 fingerprint]: https://www.rfc-editor.org/rfc/rfc9580#name-key-ids-and-fingerprints
+///
+/// ## Note
+///
+/// This type supports constructing from both uppercase and lowercase hexadecimal characters but
+/// guarantees to return the fingerprint in uppercase.
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{Error, OpenPGPv6Fingerprint};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// // Create OpenPGPv6Fingerprint from a valid OpenPGP v6 fingerprint
+/// let key = OpenPGPv6Fingerprint::from_str(
+///     "cb186c4f0609a697e4d52dfa6c722b0c1f1e27c18a56708f6525ec27bad9acc9",
+/// )?;
+/// assert_eq!(
+///     key.as_str(),
+///     "CB186C4F0609A697E4D52DFA6C722B0C1F1E27C18A56708F6525EC27BAD9ACC9"
+/// );
+///
+/// // Attempting to create a OpenPGPv6Fingerprint from an invalid fingerprint will fail
+/// assert!(OpenPGPv6Fingerprint::from_str("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OpenPGPv6Fingerprint(String);
+
+impl OpenPGPv6Fingerprint {
+    /// Creates a new `OpenPGPv6Fingerprint` instance
+    ///
+    /// See [`OpenPGPv6Fingerprint::from_str`] for more information on how the OpenPGP v6
+    /// fingerprint is validated.
+    pub fn new(fingerprint: String) -> Result<Self, Error> {
+        Self::from_str(&fingerprint)
+    }
+
+    /// Returns a reference to the inner OpenPGP v6 fingerprint as a `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Consumes the `OpenPGPv6Fingerprint` and returns the inner `String`.
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl FromStr for OpenPGPv6Fingerprint {
+    type Err = Error;
+
+    /// Creates a new `OpenPGPv6Fingerprint` instance after validating that it follows the correct
+    /// format.
+    ///
+    /// A valid OpenPGP v6 fingerprint should be exactly 64 characters long and consist only
+    /// of digits (`0-9`) and hexadecimal letters (`A-F`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OpenPGP v6 fingerprint is not valid.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() == 64 && s.chars().all(|c| c.is_ascii_hexdigit()) {
+            Ok(Self(s.to_ascii_uppercase()))
+        } else {
+            Err(Error::InvalidOpenPGPv6Fingerprint)
+        }
+    }
+}
+
+impl Display for OpenPGPv6Fingerprint {
+    /// Converts the `OpenPGPv6Fingerprint` to a uppercase `String`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 pub(crate) static PACKAGER_REGEX: Lazy<Regex> =
     lazy_regex!(r"^(?P<name>[\w\s\-().]+) <(?P<email>.*)>$");
 
@@ -402,6 +608,47 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case::key_id(
+        "2f2670ac164db36f",
+        OpenPGPKeyId::from_str("2F2670AC164DB36F").unwrap().into(),
+        "2F2670AC164DB36F"
+    )]
+    #[case::v4_fingerprint(
+        "4a0c4dffc02e1a7ed969ed231c2358a25a10d94e",
+        OpenPGPv4Fingerprint::from_str("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E").unwrap().into(),
+        "4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E"
+    )]
+    #[case::v6_fingerprint(
+        "cb186c4f0609a697e4d52dfa6c722b0c1f1e27c18a56708f6525ec27bad9acc9",
+        OpenPGPv6Fingerprint::from_str("CB186C4F0609A697E4D52DFA6C722B0C1F1E27C18A56708F6525EC27BAD9ACC9").unwrap().into(),
+        "CB186C4F0609A697E4D52DFA6C722B0C1F1E27C18A56708F6525EC27BAD9ACC9"
+    )]
+    fn openpgp_identifier_from_str(
+        #[case] input: &str,
+        #[case] expected: OpenPGPIdentifier,
+        #[case] display: &str,
+    ) -> TestResult {
+        let identifier = OpenPGPIdentifier::from_str(input)?;
+        assert_eq!(identifier, expected);
+        assert_eq!(identifier.to_string(), display);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::too_short("2F2670AC", Error::InvalidOpenPGPIdentifier("2F2670AC".to_string()))]
+    #[case::between_lengths(
+        "4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E4A0C",
+        Error::InvalidOpenPGPIdentifier("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E4A0C".to_string())
+    )]
+    #[case::invalid_v6_fingerprint(
+        "CB186C4F0609A697E4D52DFA6C722B0C1F1E27C18A56708F6525EC27BAD9ACCX",
+        Error::InvalidOpenPGPv6Fingerprint
+    )]
+    fn openpgp_identifier_from_str_fail(#[case] input: &str, #[case] expected: Error) {
+        assert_eq!(OpenPGPIdentifier::from_str(input), Err(expected));
+    }
+
     #[rstest]
     #[case("2F2670AC164DB36F")]
     #[case("584A3EBFE705CDCD")]
@@ -448,6 +695,37 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case(
+        "4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E 2F2670AC164DB36F",
+        vec![
+            OpenPGPv4Fingerprint::from_str("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E").unwrap().into(),
+            OpenPGPKeyId::from_str("2F2670AC164DB36F").unwrap().into(),
+        ]
+    )]
+    #[case(
+        "  584a3ebfe705cdcd\n\t1234567890ABCDEF1234567890ABCDEF12345678  ",
+        vec![
+            OpenPGPKeyId::from_str("584A3EBFE705CDCD").unwrap().into(),
+            OpenPGPv4Fingerprint::from_str("1234567890ABCDEF1234567890ABCDEF12345678").unwrap().into(),
+        ]
+    )]
+    #[case("", vec![])]
+    fn openpgp_identifier_parse_list(
+        #[case] input: &str,
+        #[case] expected: Vec<OpenPGPIdentifier>,
+    ) -> TestResult {
+        assert_eq!(OpenPGPIdentifier::parse_list(input)?, expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("2F2670AC164DB36F 2F2670AC164DB3", Error::InvalidOpenPGPIdentifier("2F2670AC164DB3".to_string()))]
+    #[case("invalid", Error::InvalidOpenPGPIdentifier("invalid".to_string()))]
+    fn openpgp_identifier_parse_list_invalid(#[case] input: &str, #[case] expected: Error) {
+        assert_eq!(OpenPGPIdentifier::parse_list(input), Err(expected));
+    }
+
     #[rstest]
     #[case(
         "Foobar McFooface (The Third) <foobar@mcfooface.org>",
@@ -463,8 +741,16 @@ mod tests {
             email: EmailAddress::from_str("foobar@mcfooface.org").unwrap()
         }
     )]
+    #[case(
+        "Jürgen Groß-Möller <jgm@example.org>",
+        Packager{
+            name: "Jürgen Groß-Möller".to_string(),
+            email: EmailAddress::from_str("jgm@example.org").unwrap()
+        }
+    )]
     fn valid_packager(#[case] from_str: &str, #[case] packager: Packager) {
-        assert_eq!(Packager::from_str(from_str), Ok(packager));
+        assert_eq!(Packager::from_str(from_str), Ok(packager.clone()));
+        assert_eq!(packager.to_string(), from_str);
     }
 
     /// Test that invalid packager email expressions throw the expected email errors.
@@ -473,6 +759,11 @@ mod tests {
         "Foobar McFooface <@mcfooface.org>",
         email_address::Error::LocalPartEmpty
     )]
+    #[case("Foobar McFooface <foobar@>", email_address::Error::DomainEmpty)]
+    #[case(
+        "Foobar McFooface <foobar.mcfooface.org>",
+        email_address::Error::MissingSeparator
+    )]
     #[case(
         "Foobar McFooface <foobar@mcfooface.org> <foobar@mcfoofacemcfooface.org>",
         email_address::Error::MissingEndBracket
@@ -487,6 +778,7 @@ mod tests {
     #[case("[foo] <foobar@mcfooface.org>")]
     #[case("foobar@mcfooface.org")]
     #[case("Foobar McFooface")]
+    #[case("Foobar McFooface foobar@mcfooface.org")]
     fn invalid_packager_regex(#[case] packager: &str) {
         assert_eq!(
             Packager::from_str(packager),
@@ -521,4 +813,19 @@ mod tests {
     fn packager_email(#[case] packager: Packager, #[case] email: &EmailAddress) {
         assert_eq!(email, packager.email());
     }
+
+    #[rstest]
+    #[case::fingerprint("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E", true)]
+    #[case::long_key_id("2F2670AC164DB36F 1C2358A25A10D94E", true)]
+    #[case::lowercase_long_key_id("1c2358a25a10d94e", true)]
+    #[case::other_key_id("2F2670AC164DB36F", false)]
+    #[case::other_fingerprint("B8151B117037781095514CA7BBDFFC92306B1121", false)]
+    #[case::empty("", false)]
+    fn trusted_fingerprint(#[case] trusted: &str, #[case] result: bool) -> TestResult {
+        let fingerprint =
+            OpenPGPv4Fingerprint::from_str("4A0C4DFFC02E1A7ED969ED231C2358A25A10D94E")?;
+        let trusted = OpenPGPIdentifier::parse_list(trusted)?;
+        assert_eq!(fingerprint_is_trusted(&fingerprint, &trusted), result);
+        Ok(())
+    }
 }
@@ -0,0 +1,53 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Deserializer, de};
+
+use crate::Error;
+
+/// Validates that all chars of a string slice are allowed
+///
+/// The function `allowed` is called for each char of `s`.
+///
+/// ## Errors
+///
+/// Returns an [`Error::ValueContainsInvalidChars`] for the first char that is not allowed.
+pub(crate) fn validate_charset(s: &str, allowed: impl Fn(char) -> bool) -> Result<(), Error> {
+    match s.chars().find(|c| !allowed(*c)) {
+        Some(invalid_char) => Err(Error::ValueContainsInvalidChars { invalid_char }),
+        None => Ok(()),
+    }
+}
+
+/// Deserializes a value from its string representation
+///
+/// This allows to implement [`Deserialize`] for types that are serialized using their
+/// [`Display`] implementation, by relying on their [`FromStr`] implementation.
+pub(crate) fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("foo-bar_1.0", Ok(()))]
+    #[case("", Ok(()))]
+    #[case("foo bar", Err(Error::ValueContainsInvalidChars { invalid_char: ' ' }))]
+    #[case("foo!bar?", Err(Error::ValueContainsInvalidChars { invalid_char: '!' }))]
+    fn charset(#[case] s: &str, #[case] result: Result<(), Error>) {
+        assert_eq!(
+            validate_charset(s, |c| c.is_alphanumeric() || ['-', '.', '_'].contains(&c)),
+            result
+        );
+    }
+}
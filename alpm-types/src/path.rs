@@ -1,12 +1,14 @@
 use std::{
     fmt::{Display, Formatter},
-    path::{Path, PathBuf},
+    io::{BufRead, BufReader, Read},
+    path::{Component, Path, PathBuf},
     str::FromStr,
 };
 
 use serde::Serialize;
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator};
 
-use crate::Error;
+use crate::{Error, Md5Checksum};
 
 /// A representation of an absolute path
 ///
@@ -50,6 +52,52 @@ impl AbsolutePath {
     pub fn inner(&self) -> &Path {
         &self.0
     }
+
+    /// Join a [`RelativePath`] onto the `AbsolutePath`
+    ///
+    /// As a [`RelativePath`] can neither be absolute nor escape its base directory, the result is
+    /// guaranteed to be an absolute path below `self`.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{AbsolutePath, RelativePath};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let root = AbsolutePath::from_str("/usr")?;
+    /// assert_eq!(
+    ///     root.join(&RelativePath::from_str("share/doc")?),
+    ///     AbsolutePath::from_str("/usr/share/doc")?
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn join(&self, path: &RelativePath) -> AbsolutePath {
+        AbsolutePath(self.0.join(path.inner()))
+    }
+
+    /// Return `true` if `other` is equal to or located below the `AbsolutePath`
+    ///
+    /// The check is done on whole path components and is purely lexical (i.e. symlinks and `..`
+    /// are not resolved).
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::AbsolutePath;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let root = AbsolutePath::from_str("/usr/lib")?;
+    /// assert!(root.contains(&AbsolutePath::from_str("/usr/lib/example.so")?));
+    /// assert!(!root.contains(&AbsolutePath::from_str("/usr/libexec")?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn contains(&self, other: &AbsolutePath) -> bool {
+        other.0.starts_with(&self.0)
+    }
 }
 
 impl FromStr for AbsolutePath {
@@ -117,7 +165,8 @@ pub type StartDirectory = AbsolutePath;
 /// A representation of a relative file path
 ///
 /// `RelativePath` wraps a `PathBuf` that is guaranteed to represent a
-/// relative file path (i.e. it does not end with a `/`).
+/// relative file path (i.e. it does not end with a `/`) that does not escape its base directory
+/// (e.g. `../etc/test.conf`).
 ///
 /// ## Examples
 ///
@@ -136,6 +185,10 @@ pub type StartDirectory = AbsolutePath;
 ///     RelativePath::from_str("/etc/test.conf"),
 ///     Err(Error::PathNotRelative(PathBuf::from("/etc/test.conf")))
 /// );
+/// assert_eq!(
+///     RelativePath::from_str("../etc/test.conf"),
+///     Err(Error::PathEscapesBase(PathBuf::from("../etc/test.conf")))
+/// );
 ///
 /// // Format as String
 /// assert_eq!(
@@ -150,15 +203,33 @@ pub struct RelativePath(PathBuf);
 
 impl RelativePath {
     /// Create a new `RelativePath`
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the path is not relative, ends with a `/` or escapes its base
+    /// directory using `..` components.
     pub fn new(path: PathBuf) -> Result<RelativePath, Error> {
-        match path.is_relative()
-            && !path
+        if !path.is_relative()
+            || path
                 .to_string_lossy()
                 .ends_with(std::path::MAIN_SEPARATOR_STR)
         {
-            true => Ok(RelativePath(path)),
-            false => Err(Error::PathNotRelative(path)),
+            return Err(Error::PathNotRelative(path));
+        }
+
+        let mut depth: usize = 0;
+        for component in path.components() {
+            match component {
+                Component::Normal(_) => depth += 1,
+                Component::ParentDir => match depth.checked_sub(1) {
+                    Some(parent) => depth = parent,
+                    None => return Err(Error::PathEscapesBase(path)),
+                },
+                Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+            }
         }
+
+        Ok(RelativePath(path))
     }
 
     /// Return a reference to the inner type
@@ -188,7 +259,12 @@ impl Display for RelativePath {
 
 /// The path of a packaged file that should be preserved during package operations
 ///
-/// This is a type alias for [`RelativePath`]
+/// A [`Backup`] wraps a [`RelativePath`] and optionally the [`Md5Checksum`] of the file.
+/// It is parsed from either the plain path (e.g. `etc/test.conf`) or the path and hash, separated
+/// by a colon (e.g. `etc/test.conf:d41d8cd98f00b204e9800998ecf8427e`).
+///
+/// As colons are valid in paths, the input is only split on the last colon if the remainder is a
+/// valid, lowercase Md5 hash.
 ///
 /// ## Examples
 /// ```
@@ -202,9 +278,71 @@ impl Display for RelativePath {
 ///     "etc/test.conf",
 ///     Backup::from_str("etc/test.conf")?.to_string()
 /// );
+///
+/// let backup = Backup::from_str("etc/test.conf:d41d8cd98f00b204e9800998ecf8427e")?;
+/// assert_eq!(backup.path().to_string(), "etc/test.conf");
+/// assert!(backup.hash().is_some());
+/// assert_eq!(
+///     "etc/test.conf:d41d8cd98f00b204e9800998ecf8427e",
+///     backup.to_string()
+/// );
 /// # Ok(())
 /// # }
-pub type Backup = RelativePath;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Backup {
+    path: RelativePath,
+    hash: Option<Md5Checksum>,
+}
+
+impl Backup {
+    /// Create a new `Backup`
+    pub fn new(path: RelativePath, hash: Option<Md5Checksum>) -> Self {
+        Self { path, hash }
+    }
+
+    /// Return a reference to the path
+    pub fn path(&self) -> &RelativePath {
+        &self.path
+    }
+
+    /// Return a reference to the optional hash
+    pub fn hash(&self) -> Option<&Md5Checksum> {
+        self.hash.as_ref()
+    }
+}
+
+impl FromStr for Backup {
+    type Err = Error;
+
+    /// Parses a backup path with an optional hash from a string
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is not relative
+    fn from_str(s: &str) -> Result<Backup, Self::Err> {
+        if let Some((path, hash)) = s.rsplit_once(':') {
+            // Only consider the remainder a hash, if it is displayed exactly as it is provided.
+            match Md5Checksum::from_str(hash) {
+                Ok(checksum) if checksum.to_string() == hash => {
+                    return Ok(Self::new(RelativePath::from_str(path)?, Some(checksum)));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(Self::new(RelativePath::from_str(s)?, None))
+    }
+}
+
+impl Display for Backup {
+    fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
+        match &self.hash {
+            Some(hash) => write!(fmt, "{}:{hash}", self.path),
+            None => write!(fmt, "{}", self.path),
+        }
+    }
+}
 
 /// A special install script that is to be included in the package
 ///
@@ -226,6 +364,108 @@ pub type Backup = RelativePath;
 /// # }
 pub type Install = RelativePath;
 
+/// A function that may be defined in an [`Install`] scriptlet
+///
+/// Each hook is called by the package manager at a specific point of a package operation.
+///
+/// ## Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::InstallHook;
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// assert_eq!(
+///     InstallHook::from_str("post_upgrade")?,
+///     InstallHook::PostUpgrade
+/// );
+/// assert_eq!(InstallHook::PreRemove.to_string(), "pre_remove");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Display, EnumIter, EnumString, Eq, Hash, PartialEq, Serialize)]
+pub enum InstallHook {
+    /// Run before the package is installed
+    #[strum(to_string = "pre_install")]
+    PreInstall,
+    /// Run after the package is installed
+    #[strum(to_string = "post_install")]
+    PostInstall,
+    /// Run before the package is upgraded
+    #[strum(to_string = "pre_upgrade")]
+    PreUpgrade,
+    /// Run after the package is upgraded
+    #[strum(to_string = "post_upgrade")]
+    PostUpgrade,
+    /// Run before the package is removed
+    #[strum(to_string = "pre_remove")]
+    PreRemove,
+    /// Run after the package is removed
+    #[strum(to_string = "post_remove")]
+    PostRemove,
+}
+
+/// Detects the [`InstallHook`]s defined in the contents of an [`Install`] scriptlet
+///
+/// A hook is defined by a line declaring a shell function of the same name (e.g.
+/// `post_install() {` or `function post_install {`), regardless of its indentation.
+/// Lines that are commented out are ignored.
+/// Each hook is returned once, in the order of its first definition.
+///
+/// ## Errors
+///
+/// Returns an error if reading from `reader` fails or its contents are not valid UTF-8.
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{InstallHook, scan_install_hooks};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let scriptlet = "post_install() {\n  echo installed\n}\n\n# pre_remove() {\n#}\n";
+/// assert_eq!(
+///     scan_install_hooks(scriptlet.as_bytes())?,
+///     vec![InstallHook::PostInstall]
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn scan_install_hooks<R: Read>(reader: R) -> Result<Vec<InstallHook>, Error> {
+    let mut hooks = Vec::new();
+    for line in BufReader::new(reader).lines() {
+        let line = line.map_err(|error| Error::IoError {
+            context: "reading an install scriptlet",
+            source: error.into(),
+        })?;
+        let line = line.trim_start();
+        if line.starts_with('#') {
+            continue;
+        }
+
+        // Functions may be declared using `name()` or `function name`, followed by `()`
+        // optionally.
+        let (declaration, has_keyword) = match line.strip_prefix("function") {
+            Some(rest) if rest.starts_with(char::is_whitespace) => (rest.trim_start(), true),
+            _ => (line, false),
+        };
+        let end = declaration
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(declaration.len());
+        let (name, rest) = declaration.split_at(end);
+        let rest = rest.trim_start();
+        if !(rest.starts_with("()") || (has_keyword && (rest.is_empty() || rest.starts_with('{'))))
+        {
+            continue;
+        }
+
+        match InstallHook::iter().find(|hook| hook.to_string() == name) {
+            Some(hook) if !hooks.contains(&hook) => hooks.push(hook),
+            _ => {}
+        }
+    }
+
+    Ok(hooks)
+}
+
 /// The relative path to a changelog file that may be included in a package
 ///
 /// This is a type alias for [`RelativePath`]
@@ -280,9 +520,137 @@ mod tests {
     #[case("etc", RelativePath::new(PathBuf::from("etc")))]
     #[case(
         "../etc/test.conf",
-        RelativePath::new(PathBuf::from("../etc/test.conf"))
+        Err(Error::PathEscapesBase(PathBuf::from("../etc/test.conf")))
+    )]
+    #[case(
+        "etc/../../test.conf",
+        Err(Error::PathEscapesBase(PathBuf::from("etc/../../test.conf")))
     )]
+    #[case(
+        "etc/../test.conf",
+        RelativePath::new(PathBuf::from("etc/../test.conf"))
+    )]
+    #[case("./etc/test.conf", RelativePath::new(PathBuf::from("./etc/test.conf")))]
     fn relative_path_from_str(#[case] s: &str, #[case] result: Result<RelativePath, Error>) {
         assert_eq!(RelativePath::from_str(s), result);
     }
+
+    #[rstest]
+    #[case("/", "etc/test.conf", "/etc/test.conf")]
+    #[case("/usr", "share/doc/example", "/usr/share/doc/example")]
+    #[case("/usr/", "lib", "/usr/lib")]
+    fn absolute_path_join(
+        #[case] base: &str,
+        #[case] path: &str,
+        #[case] expected: &str,
+    ) -> testresult::TestResult<()> {
+        let joined = AbsolutePath::from_str(base)?.join(&RelativePath::from_str(path)?);
+        assert!(joined.inner().is_absolute());
+        assert_eq!(joined, AbsolutePath::from_str(expected)?);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("/usr/lib", "/usr/lib", true)]
+    #[case("/usr/lib", "/usr/lib/example.so", true)]
+    #[case("/", "/etc", true)]
+    #[case("/usr/lib", "/usr/libexec", false)]
+    #[case("/usr/lib", "/usr/share/lib", false)]
+    #[case("/usr/lib", "/usr", false)]
+    fn absolute_path_contains(
+        #[case] base: &str,
+        #[case] other: &str,
+        #[case] contains: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            AbsolutePath::from_str(base)?.contains(&AbsolutePath::from_str(other)?),
+            contains
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn install_hooks() -> testresult::TestResult<()> {
+        let scriptlet = r#"
+pre_upgrade() {
+    echo "pre upgrade"
+}
+
+  post_upgrade () {
+    post_install
+}
+
+# post_remove() {
+#     echo "disabled"
+# }
+    #pre_remove() {
+
+function post_install {
+    echo "post install"
+}
+
+pre_upgrade() {
+    true
+}
+
+pre_install_helper() {
+    true
+}
+"#;
+        assert_eq!(
+            scan_install_hooks(scriptlet.as_bytes())?,
+            vec![
+                InstallHook::PreUpgrade,
+                InstallHook::PostUpgrade,
+                InstallHook::PostInstall,
+            ]
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(InstallHook::PreInstall, "pre_install")]
+    #[case(InstallHook::PostInstall, "post_install")]
+    #[case(InstallHook::PreUpgrade, "pre_upgrade")]
+    #[case(InstallHook::PostUpgrade, "post_upgrade")]
+    #[case(InstallHook::PreRemove, "pre_remove")]
+    #[case(InstallHook::PostRemove, "post_remove")]
+    fn install_hook_roundtrip(
+        #[case] hook: InstallHook,
+        #[case] name: &str,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(hook.to_string(), name);
+        assert_eq!(InstallHook::from_str(name)?, hook);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::path_only("etc/foo.conf", "etc/foo.conf", None)]
+    #[case::path_with_hash(
+        "etc/foo.conf:d41d8cd98f00b204e9800998ecf8427e",
+        "etc/foo.conf",
+        Some("d41d8cd98f00b204e9800998ecf8427e")
+    )]
+    #[case::colon_in_path("etc/foo:bar.conf", "etc/foo:bar.conf", None)]
+    #[case::colon_in_path_with_hash(
+        "etc/foo:bar.conf:d41d8cd98f00b204e9800998ecf8427e",
+        "etc/foo:bar.conf",
+        Some("d41d8cd98f00b204e9800998ecf8427e")
+    )]
+    #[case::uppercase_hash(
+        "etc/foo.conf:D41D8CD98F00B204E9800998ECF8427E",
+        "etc/foo.conf:D41D8CD98F00B204E9800998ECF8427E",
+        None
+    )]
+    fn backup_from_str(
+        #[case] input: &str,
+        #[case] path: &str,
+        #[case] hash: Option<&str>,
+    ) -> testresult::TestResult<()> {
+        let backup = Backup::from_str(input)?;
+        assert_eq!(backup.path().to_string(), path);
+        assert_eq!(backup.hash().map(ToString::to_string).as_deref(), hash);
+        assert_eq!(backup.to_string(), input);
+        Ok(())
+    }
 }
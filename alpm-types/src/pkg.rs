@@ -3,7 +3,7 @@ use std::{fmt::Display, str::FromStr};
 use serde::Serialize;
 use strum::{Display, EnumString};
 
-use crate::{Error, Name};
+use crate::{Error, Name, Version};
 
 /// The type of a package
 ///
@@ -74,6 +74,61 @@ pub type PackageDescription = String;
 /// ```
 pub type PackageBaseName = Name;
 
+/// Derives the name and version of the debug package of a package
+///
+/// Debug packages are named after the package they provide debug symbols for, suffixed with
+/// `-debug`, and share its version.
+///
+/// ## Examples
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{Name, Version, debug_package};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let (name, version) = debug_package(&Name::new("gcc")?, &Version::from_str("14.2.1-1")?);
+/// assert_eq!(name, Name::new("gcc-debug")?);
+/// assert_eq!(version, Version::from_str("14.2.1-1")?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn debug_package(name: &Name, version: &Version) -> (Name, Version) {
+    // Appending `-debug` to a valid Name always yields a valid Name, as the suffix only consists
+    // of allowed characters and the first character remains untouched.
+    let name = Name::new(&format!("{name}-debug"))
+        .expect("a valid Name suffixed with -debug is a valid Name");
+    (name, version.clone())
+}
+
+/// Returns `true` if a package is an automatically created debug package
+///
+/// This is the case if the [`PackageType`] of the package is [`PackageType::Debug`] and its
+/// [`Name`] is suffixed with `-debug` (see [`debug_package`]).
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{Name, PackageType, is_auto_debug_package};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// assert!(is_auto_debug_package(
+///     &Name::new("gcc-debug")?,
+///     PackageType::Debug
+/// ));
+/// assert!(!is_auto_debug_package(
+///     &Name::new("gcc-debug")?,
+///     PackageType::Package
+/// ));
+/// assert!(!is_auto_debug_package(
+///     &Name::new("gcc")?,
+///     PackageType::Debug
+/// ));
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_auto_debug_package(name: &Name, package_type: PackageType) -> bool {
+    package_type == PackageType::Debug && name.as_ref().ends_with("-debug")
+}
+
 /// Extra data associated with a package
 ///
 /// This type wraps a key-value pair of data as String, which is separated by an equal sign (`=`).
@@ -149,6 +204,44 @@ impl Display for ExtraData {
     }
 }
 
+impl TryFrom<&ExtraData> for PackageType {
+    type Error = Error;
+
+    /// Creates a [`PackageType`] from an [`ExtraData`] entry with the `pkgtype` key
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the key of the [`ExtraData`] is not `pkgtype` or if its value is not a
+    /// valid [`PackageType`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{ExtraData, PackageType};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     PackageType::try_from(&ExtraData::from_str("pkgtype=debug")?)?,
+    ///     PackageType::Debug
+    /// );
+    /// assert!(PackageType::try_from(&ExtraData::from_str("foo=debug")?).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_from(extra_data: &ExtraData) -> Result<Self, Self::Error> {
+        const KEY: &str = "pkgtype";
+        if extra_data.key() != KEY {
+            return Err(Error::UnexpectedExtraDataKey {
+                key: extra_data.key().to_string(),
+                expected: KEY,
+            });
+        }
+
+        Ok(PackageType::from_str(extra_data.value())?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -179,10 +272,48 @@ mod tests {
         assert_eq!(pkgtype_str, format!("{}", pkgtype));
     }
 
+    #[rstest]
+    #[case(PackageType::Debug)]
+    #[case(PackageType::Package)]
+    #[case(PackageType::Source)]
+    #[case(PackageType::Split)]
+    fn pkgtype_roundtrip(#[case] pkgtype: PackageType) -> testresult::TestResult<()> {
+        assert_eq!(PackageType::from_str(&pkgtype.to_string())?, pkgtype);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("Pkg")]
+    #[case("srcinfo")]
+    #[case("")]
+    fn pkgtype_from_string_error(#[case] from_str: &str) {
+        assert_eq!(
+            PackageType::from_str(from_str).map_err(Error::from),
+            Err(Error::InvalidVariant(strum::ParseError::VariantNotFound))
+        );
+    }
+
+    #[rstest]
+    #[case("gcc", "1:14.2.1-1", "gcc-debug")]
+    #[case("gtk2+", "2.24.33-5", "gtk2+-debug")]
+    #[case("python-foo", "1.0.0", "python-foo-debug")]
+    fn debug_package_name_and_version(
+        #[case] name: &str,
+        #[case] version: &str,
+        #[case] debug_name: &str,
+    ) -> testresult::TestResult<()> {
+        let version = Version::from_str(version)?;
+        let (name, debug_version) = debug_package(&Name::new(name)?, &version);
+        assert_eq!(name, Name::new(debug_name)?);
+        assert_eq!(debug_version, version);
+        Ok(())
+    }
+
     #[rstest]
     #[case("key=value", "key", "value")]
     #[case("pkgtype=debug", "pkgtype", "debug")]
     #[case("test-123@.foo_+=1000", "test-123@.foo_+", "1000")]
+    #[case("key=value=with=delimiters", "key", "value=with=delimiters")]
     fn extra_data_from_str(
         #[case] data: &str,
         #[case] key: &str,
@@ -205,4 +336,45 @@ mod tests {
     ) {
         assert_eq!(ExtraData::from_str(extra_data), result);
     }
+
+    #[rstest]
+    #[case("pkgtype=pkg", Ok(PackageType::Package))]
+    #[case("pkgtype=debug", Ok(PackageType::Debug))]
+    #[case("pkgtype=src", Ok(PackageType::Source))]
+    #[case(
+        "pkgtype=foo",
+        Err(Error::InvalidVariant(strum::ParseError::VariantNotFound))
+    )]
+    #[case(
+        "foo=pkg",
+        Err(Error::UnexpectedExtraDataKey { key: "foo".to_string(), expected: "pkgtype" })
+    )]
+    fn pkgtype_from_extra_data(
+        #[case] extra_data: &str,
+        #[case] result: Result<PackageType, Error>,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            PackageType::try_from(&ExtraData::from_str(extra_data)?),
+            result
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("gcc-debug", PackageType::Debug, true)]
+    #[case("gcc-debug", PackageType::Package, false)]
+    #[case("gcc-debug", PackageType::Split, false)]
+    #[case("gcc", PackageType::Debug, false)]
+    #[case("debug", PackageType::Debug, false)]
+    fn auto_debug_package(
+        #[case] name: &str,
+        #[case] package_type: PackageType,
+        #[case] expected: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            is_auto_debug_package(&Name::new(name)?, package_type),
+            expected
+        );
+        Ok(())
+    }
 }
@@ -1,9 +1,10 @@
 use std::{
+    collections::HashSet,
     fmt::{Display, Formatter},
     str::FromStr,
 };
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use winnow::{
     ModalResult,
@@ -21,8 +22,10 @@ use crate::{
     Name,
     PackageVersion,
     SharedObjectName,
+    Version,
     VersionComparison,
     VersionRequirement,
+    parse::validate_charset,
 };
 
 /// Provides either a [`PackageVersion`] or a [`SharedObjectName`].
@@ -203,6 +206,9 @@ pub enum SonameV1 {
     /// `architecture`. This form is used if the _soname data_ of a _shared object_ exposes a
     /// specific version.
     ///
+    /// The trailing `-32` or `-64` denotes the `architecture` and is never part of the `version`
+    /// (i.e. it is not a pkgrel).
+    ///
     /// # Examples
     ///
     /// ```
@@ -736,7 +742,10 @@ impl Display for SonameV2 {
 ///
 /// A [`PackageRelation`] covers all **alpm-package-relations** *except* optional
 /// dependencies, as those behave differently.
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+///
+/// [`PackageRelation`]s are ordered by name first and by their (optional) version requirement
+/// second, where a relation without a version requirement is ordered before one with it.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct PackageRelation {
     /// The name of the package
     pub name: Name,
@@ -771,6 +780,147 @@ impl PackageRelation {
             version_requirement,
         }
     }
+
+    /// Returns `true` if a package of a given name and version satisfies the relation
+    ///
+    /// The `name` must match the name of the relation.
+    /// A relation without a version requirement is satisfied by any `version`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{Name, PackageRelation, Version};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let name = Name::new("example")?;
+    /// let version = Version::from_str("1.0.0-1")?;
+    ///
+    /// assert!(PackageRelation::from_str("example")?.satisfied_by(&name, &version));
+    /// assert!(PackageRelation::from_str("example>=1.0")?.satisfied_by(&name, &version));
+    /// assert!(!PackageRelation::from_str("example>1.0.0")?.satisfied_by(&name, &version));
+    /// assert!(!PackageRelation::from_str("other")?.satisfied_by(&name, &version));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn satisfied_by(&self, name: &Name, version: &Version) -> bool {
+        self.name == *name
+            && self
+                .version_requirement
+                .as_ref()
+                .is_none_or(|requirement| requirement.is_satisfied_by(version))
+    }
+
+    /// Returns `true` if any of the provided relations satisfies the relation
+    ///
+    /// A provision matches if its name equals that of the relation and either
+    ///
+    /// - the relation has no version requirement, or
+    /// - the provision carries an exact (`=`) version that satisfies the version requirement.
+    ///
+    /// As with pacman, a provision without a version never satisfies a versioned relation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::PackageRelation;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let provides = [
+    ///     PackageRelation::from_str("sh=5.2")?,
+    ///     PackageRelation::from_str("libexample")?,
+    /// ];
+    ///
+    /// assert!(PackageRelation::from_str("sh>=5")?.matches_provides(&provides));
+    /// assert!(PackageRelation::from_str("libexample")?.matches_provides(&provides));
+    /// assert!(!PackageRelation::from_str("libexample>=1.0")?.matches_provides(&provides));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches_provides(&self, provides: &[PackageRelation]) -> bool {
+        provides.iter().any(|provide| {
+            provide.name == self.name
+                && match (&self.version_requirement, &provide.version_requirement) {
+                    (None, _) => true,
+                    (Some(requirement), Some(provision)) => {
+                        provision.comparison == VersionComparison::Equal
+                            && requirement.is_satisfied_by(&provision.version)
+                    }
+                    (Some(_), None) => false,
+                }
+        })
+    }
+
+    /// Returns the relation as a versioned [`SonameV1`], if it is a soname dependency
+    ///
+    /// The [alpm-sonamev1] forms `name=version-architecture` and `name=soname-architecture`
+    /// overlap with the [`PackageRelation`] form `name=pkgver-pkgrel`.
+    /// A relation is considered a soname dependency if its name is a [`SharedObjectName`], its
+    /// version requirement uses `=` and the trailing component is a valid
+    /// [`ElfArchitectureFormat`] instead of a pkgrel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{ElfArchitectureFormat, PackageRelation, SonameV1};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     PackageRelation::from_str("libcrypto.so=3-64")?.soname(),
+    ///     Some(SonameV1::Explicit {
+    ///         name: "libcrypto.so".parse()?,
+    ///         version: "3".parse()?,
+    ///         architecture: ElfArchitectureFormat::Bit64,
+    ///     })
+    /// );
+    /// assert_eq!(PackageRelation::from_str("glibc>=2.38")?.soname(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [alpm-sonamev1]: https://alpm.archlinux.page/specifications/alpm-sonamev1.7.html
+    pub fn soname(&self) -> Option<SonameV1> {
+        if !matches!(
+            self.version_requirement,
+            Some(VersionRequirement {
+                comparison: VersionComparison::Equal,
+                ..
+            })
+        ) {
+            return None;
+        }
+
+        match SonameV1::from_str(&self.to_string()) {
+            Ok(SonameV1::Basic(_)) | Err(_) => None,
+            Ok(soname) => Some(soname),
+        }
+    }
+
+    /// Returns `true` if the relation is a soname dependency
+    ///
+    /// See [`PackageRelation::soname`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::PackageRelation;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert!(PackageRelation::from_str("libcrypto.so=3-64")?.is_soname());
+    /// assert!(!PackageRelation::from_str("example=3-64")?.is_soname());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_soname(&self) -> bool {
+        self.soname().is_some()
+    }
 }
 
 impl Display for PackageRelation {
@@ -789,7 +939,9 @@ impl FromStr for PackageRelation {
     ///
     /// # Errors
     ///
-    /// Returns an error if a [`PackageRelation`] can not be parsed from input.
+    /// Returns an [`Error::InvalidRelation`] if a [`PackageRelation`] can not be parsed from
+    /// input. The [`RelationPart`] of the error indicates which part of the input is invalid and
+    /// its source (if any) the reason why.
     ///
     /// # Examples
     ///
@@ -860,31 +1012,273 @@ impl FromStr for PackageRelation {
     /// );
     ///
     /// assert!(PackageRelation::from_str("example<").is_err());
+    /// assert!(PackageRelation::from_str("example=>1.0.0").is_err());
     /// # Ok(())
     /// # }
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const OPERATOR_CHARS: [char; 3] = ['<', '=', '>'];
+        let invalid = |part, source: Option<Error>| Error::InvalidRelation {
+            input: s.to_string(),
+            part,
+            source: source.map(Box::new),
+        };
+
         // NOTE: The string splitting relies on the specific ordering of the VersionComparison
         // variants (which orders two-letter comparators over one-letter ones)!
         for comparison in VersionComparison::iter() {
             if let Some((name, version)) = s.split_once(comparison.as_ref()) {
+                // Leftover comparison characters indicate an invalid operator (e.g. `=>` or `<<`)
+                if name.ends_with(OPERATOR_CHARS) || version.starts_with(OPERATOR_CHARS) {
+                    return Err(invalid(RelationPart::Operator, None));
+                }
+
                 return Ok(Self {
-                    name: Name::new(name)?,
+                    name: Name::new(name)
+                        .map_err(|error| invalid(RelationPart::Name, Some(error)))?,
                     version_requirement: Some(VersionRequirement {
                         comparison,
-                        version: version.parse()?,
+                        version: version
+                            .parse()
+                            .map_err(|error| invalid(RelationPart::Version, Some(error)))?,
                     }),
                 });
             }
         }
 
         Ok(Self {
-            name: Name::new(s)?,
+            name: Name::new(s).map_err(|error| invalid(RelationPart::Name, Some(error)))?,
             version_requirement: None,
         })
     }
 }
 
+/// A part of a [`PackageRelation`]
+///
+/// This is used in [`Error::InvalidRelation`] to indicate which part of a package relation is
+/// invalid.
+#[derive(Clone, Copy, Debug, strum::Display, Eq, PartialEq, Serialize)]
+#[strum(serialize_all = "lowercase")]
+pub enum RelationPart {
+    /// The name of the package relation
+    Name,
+    /// The comparison operator of the version requirement
+    Operator,
+    /// The version of the version requirement
+    Version,
+}
+
+/// Renders a list of [`PackageRelation`]s as a newline-separated block
+///
+/// The relations are sorted and deduplicated before rendering, which creates a canonical
+/// representation as it is used e.g. in the `%DEPENDS%` section of a pacman `desc` file.
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{PackageRelation, relations_to_block};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let relations = [
+///     PackageRelation::from_str("foo>=1.0")?,
+///     PackageRelation::from_str("bar")?,
+///     PackageRelation::from_str("foo>=1.0")?,
+/// ];
+/// assert_eq!(relations_to_block(&relations), "bar\nfoo>=1.0");
+/// # Ok(())
+/// # }
+/// ```
+pub fn relations_to_block(relations: &[PackageRelation]) -> String {
+    let mut relations = relations.iter().collect::<Vec<_>>();
+    relations.sort();
+    relations.dedup();
+
+    relations
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the distinct [`Name`]s of a list of [`PackageRelation`]s
+///
+/// The version requirements of the relations are ignored.
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{Name, PackageRelation, relation_names};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let relations = [
+///     PackageRelation::from_str("glibc>=2.40")?,
+///     PackageRelation::from_str("glibc<3")?,
+///     PackageRelation::from_str("bash")?,
+/// ];
+/// let names = relation_names(&relations);
+///
+/// assert_eq!(names.len(), 2);
+/// assert!(names.contains(&Name::new("glibc")?));
+/// assert!(names.contains(&Name::new("bash")?));
+/// # Ok(())
+/// # }
+/// ```
+pub fn relation_names(relations: &[PackageRelation]) -> HashSet<Name> {
+    relations
+        .iter()
+        .map(|relation| relation.name.clone())
+        .collect()
+}
+
+/// Returns `true` if a dependency is satisfied by any package in a set of candidates
+///
+/// Each candidate is described by its [`Name`], its [`Version`] and the list of
+/// [`PackageRelation`]s it provides.
+/// A candidate satisfies `dependency` if
+///
+/// - its name matches and its version satisfies the (optional) version requirement of `dependency`,
+///   or
+/// - one of its provisions matches the name of `dependency` and either `dependency` has no version
+///   requirement, or the provision carries an exact (`=`) version that satisfies it.
+///
+/// As with pacman, a provision without a version never satisfies a versioned dependency.
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{Name, PackageRelation, Version, is_dependency_satisfied};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let provides = [PackageRelation::from_str("sh=5.2")?];
+/// let candidates = [(
+///     Name::new("bash")?,
+///     Version::from_str("5.2.37-1")?,
+///     provides.as_slice(),
+/// )];
+///
+/// assert!(is_dependency_satisfied(
+///     &PackageRelation::from_str("bash>=5")?,
+///     &candidates
+/// ));
+/// assert!(is_dependency_satisfied(
+///     &PackageRelation::from_str("sh>=5")?,
+///     &candidates
+/// ));
+/// assert!(!is_dependency_satisfied(
+///     &PackageRelation::from_str("zsh")?,
+///     &candidates
+/// ));
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_dependency_satisfied(
+    dependency: &PackageRelation,
+    candidates: &[(Name, Version, &[PackageRelation])],
+) -> bool {
+    candidates.iter().any(|(name, version, provides)| {
+        dependency.satisfied_by(name, version) || dependency.matches_provides(provides)
+    })
+}
+
+/// Returns `true` if a package matches a relation pattern, that uses globs in its name
+///
+/// The `pattern` is a package relation (e.g. `python*>=3.12`), whose name may contain the
+/// wildcards `*` (matching any number of characters) and `?` (matching exactly one character).
+/// As a [`Name`] can not contain these characters, the pattern is not a [`PackageRelation`].
+/// Only the name is matched using globs, while the (optional) version requirement is applied to
+/// `version` as in [`PackageRelation::satisfied_by`].
+///
+/// ## Errors
+///
+/// Returns an error if the name of `pattern` contains characters that are neither allowed in a
+/// [`Name`] nor wildcards, or if its version requirement is invalid.
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{Name, Version, relation_glob_matches};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let version = Version::from_str("2.32.3-1")?;
+/// assert!(relation_glob_matches(
+///     "python*",
+///     &Name::new("python-requests")?,
+///     &version
+/// )?);
+/// assert!(!relation_glob_matches(
+///     "python*",
+///     &Name::new("ruby")?,
+///     &version
+/// )?);
+/// assert!(!relation_glob_matches(
+///     "python-*>=3",
+///     &Name::new("python-requests")?,
+///     &version
+/// )?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn relation_glob_matches(pattern: &str, name: &Name, version: &Version) -> Result<bool, Error> {
+    let (glob, requirement) = match pattern.find(['<', '=', '>']) {
+        Some(index) => {
+            let (glob, requirement) = pattern.split_at(index);
+            (glob, Some(VersionRequirement::from_str(requirement)?))
+        }
+        None => (pattern, None),
+    };
+    // Wildcards may replace any character of a name, so validate the glob with a placeholder.
+    Name::from_str(&glob.replace(['*', '?'], "a")).map_err(|error| match error {
+        Error::InvalidName { rule, .. } => Error::InvalidName {
+            name: glob.to_string(),
+            rule,
+        },
+        error => error,
+    })?;
+
+    Ok(glob_matches(glob.as_bytes(), name.as_ref().as_bytes())
+        && requirement.is_none_or(|requirement| requirement.is_satisfied_by(version)))
+}
+
+/// Returns `true` if `input` matches `glob`, in which `*` and `?` are wildcards
+///
+/// Both are ASCII only, as guaranteed for [`Name`].
+fn glob_matches(glob: &[u8], input: &[u8]) -> bool {
+    let (mut glob_index, mut input_index) = (0, 0);
+    // The position of the last `*` in `glob` and the position in `input` it has been matched to.
+    let mut backtrack = None;
+    while input_index < input.len() {
+        match glob.get(glob_index) {
+            Some(b'*') => {
+                backtrack = Some((glob_index, input_index));
+                glob_index += 1;
+            }
+            Some(&char) if char == b'?' || char == input[input_index] => {
+                glob_index += 1;
+                input_index += 1;
+            }
+            // Let the last `*` consume one more character and retry.
+            _ => match backtrack {
+                Some((star_index, star_input_index)) => {
+                    backtrack = Some((star_index, star_input_index + 1));
+                    glob_index = star_index + 1;
+                    input_index = star_input_index + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    glob[glob_index..].iter().all(|char| *char == b'*')
+}
+
 /// An optional dependency for a package.
 ///
 /// This type is used for representing dependencies that are not essential for base functionality
@@ -894,7 +1288,12 @@ impl FromStr for PackageRelation {
 /// by a colon (`:`).
 ///
 /// - The package relation component must be a valid [`PackageRelation`].
-/// - If a description is provided it must be at least one character long.
+/// - If a description is provided it must be at least one character long and must be a valid
+///   [`PackageDescription`](crate::PackageDescription) (i.e. it must not contain newlines).
+///
+/// [`OptionalDependency`]s are ordered by their [`PackageRelation`] first and by their
+/// description second, which allows to create a deterministic order for lists of them (see
+/// [`OptionalDependency::sort_and_dedup`]).
 ///
 /// ## Examples
 ///
@@ -913,7 +1312,7 @@ impl FromStr for PackageRelation {
 /// // Get the description
 /// assert_eq!(
 ///     Some("this is an example dependency"),
-///     opt_depend.description().as_deref()
+///     opt_depend.description()
 /// );
 ///
 /// // Format as String
@@ -924,7 +1323,7 @@ impl FromStr for PackageRelation {
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct OptionalDependency {
     package_relation: PackageRelation,
     description: Option<String>,
@@ -947,14 +1346,53 @@ impl OptionalDependency {
         &self.package_relation.name
     }
 
+    /// Return the package relation of the optional dependency
+    pub fn package_relation(&self) -> &PackageRelation {
+        &self.package_relation
+    }
+
     /// Return the version requirement of the optional dependency
     pub fn version_requirement(&self) -> &Option<VersionRequirement> {
         &self.package_relation.version_requirement
     }
 
     /// Return the description for the optional dependency, if it exists
-    pub fn description(&self) -> &Option<String> {
-        &self.description
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Sorts a list of [`OptionalDependency`]s and removes duplicate entries from it
+    ///
+    /// This creates a canonical representation of a list of optional dependencies, which is
+    /// independent of the order in which they have been defined.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::OptionalDependency;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let mut opt_depends = vec![
+    ///     OptionalDependency::from_str("foo: for foo support")?,
+    ///     OptionalDependency::from_str("bar")?,
+    ///     OptionalDependency::from_str("foo: for foo support")?,
+    /// ];
+    /// OptionalDependency::sort_and_dedup(&mut opt_depends);
+    /// assert_eq!(
+    ///     opt_depends,
+    ///     vec![
+    ///         OptionalDependency::from_str("bar")?,
+    ///         OptionalDependency::from_str("foo: for foo support")?,
+    ///     ]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn sort_and_dedup(optional_dependencies: &mut Vec<OptionalDependency>) {
+        optional_dependencies.sort();
+        optional_dependencies.dedup();
     }
 }
 
@@ -962,16 +1400,24 @@ impl FromStr for OptionalDependency {
     type Err = Error;
 
     /// Create an OptionalDependency from a string slice
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if
+    ///
+    /// - the part before the colon is not a valid [`PackageRelation`],
+    /// - or the description contains a newline.
     fn from_str(s: &str) -> Result<OptionalDependency, Self::Err> {
-        if let Some((name, description)) = s.split_once(":") {
+        if let Some((relation, description)) = s.split_once(":") {
             let description = description.trim_start();
-            let relation = PackageRelation::from_str(name)?;
+            validate_charset(description, |c| c != '\n' && c != '\r')?;
+            let relation = PackageRelation::from_str(relation)?;
             Ok(Self::new(
                 relation,
                 (!description.is_empty()).then_some(description.to_string()),
             ))
         } else {
-            Ok(Self::new(PackageRelation::new(Name::new(s)?, None), None))
+            Ok(Self::new(PackageRelation::from_str(s)?, None))
         }
     }
 }
@@ -979,8 +1425,8 @@ impl FromStr for OptionalDependency {
 impl Display for OptionalDependency {
     fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
         match self.description {
-            Some(ref description) => write!(fmt, "{}: {}", self.name(), description),
-            None => write!(fmt, "{}", self.name()),
+            Some(ref description) => write!(fmt, "{}: {}", self.package_relation, description),
+            None => write!(fmt, "{}", self.package_relation),
         }
     }
 }
@@ -991,7 +1437,7 @@ impl Display for OptionalDependency {
 /// characteristic or functionality.
 ///
 /// While group names can be any valid UTF-8 string, it is recommended to follow
-/// the format of [`Name`] (`[a-z\d\-._@+]` but must not start with `[-.]`)
+/// the format of [`Name`] (`[a-zA-Z\d\-._@+]` but must not start with `[-.+]`)
 /// to ensure consistency and ease of use.
 ///
 /// This is a type alias for [`String`].
@@ -1005,19 +1451,95 @@ impl Display for OptionalDependency {
 /// ```
 pub type Group = String;
 
+/// Validates that a [`Group`] follows the recommended format of [`Name`]
+///
+/// As [`Group`] allows arbitrary strings, this is not enforced when creating a [`Group`], but can
+/// be used to ensure consistent group names.
+///
+/// ## Errors
+///
+/// Returns an [`Error::InvalidName`] if `group` is not a valid [`Name`].
+///
+/// ## Examples
+/// ```
+/// use alpm_types::validate_group;
+///
+/// assert!(validate_group("base-devel").is_ok());
+/// assert!(validate_group("-base").is_err());
+/// assert!(validate_group("my group").is_err());
+/// ```
+pub fn validate_group(group: &str) -> Result<(), Error> {
+    Name::from_str(group).map(|_| ())
+}
+
+/// The [`Group`]s a package is a member of
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{GroupMembership, Name};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let membership = GroupMembership::new(
+///     Name::new("gcc")?,
+///     vec!["base-devel".to_string(), "toolchain".to_string()],
+/// )?;
+/// assert!(membership.contains("toolchain"));
+/// assert!(!membership.contains("base"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct GroupMembership {
+    name: Name,
+    groups: Vec<Group>,
+}
+
+impl GroupMembership {
+    /// Creates a new [`GroupMembership`] of the package `name` in `groups`
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if any of the `groups` is not valid according to [`validate_group`].
+    pub fn new(name: Name, groups: Vec<Group>) -> Result<Self, Error> {
+        for group in groups.iter() {
+            validate_group(group)?;
+        }
+
+        Ok(Self { name, groups })
+    }
+
+    /// Returns the name of the package
+    pub fn name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the groups the package is a member of
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+
+    /// Returns `true` if the package is a member of `group`
+    pub fn contains(&self, group: &str) -> bool {
+        self.groups.iter().any(|member| member == group)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use proptest::{prop_assert_eq, proptest, test_runner::Config as ProptestConfig};
     use rstest::rstest;
 
     use super::*;
+    use crate::NameRule;
 
     const COMPARATOR_REGEX: &str = r"(<|<=|=|>=|>)";
-    /// NOTE: [`Epoch`][alpm_types::Epoch] is implicitly constrained by [`std::usize::MAX`].
+    /// NOTE: [`Epoch`][alpm_types::Epoch] is implicitly constrained by [`std::u64::MAX`].
     /// However, it's unrealistic to ever reach that many forced downgrades for a package, hence
     /// we don't test that fully
     const EPOCH_REGEX: &str = r"[1-9]{1}[0-9]{0,10}";
-    const NAME_REGEX: &str = r"[a-z0-9_@+]+[a-z0-9\-._@+]*";
+    const NAME_REGEX: &str = r"[a-z0-9_@][a-z0-9\-._@+]*";
     const PKGREL_REGEX: &str = r"[1-9]+[0-9]*(|[.]{1}[1-9]{1}[0-9]*)";
     const PKGVER_REGEX: &str = r"([[:alnum:]][[:alnum:]_+.]*)";
     const DESCRIPTION_REGEX: &str = r"[[:alnum:]][[:alnum:] _+.,-]*";
@@ -1087,18 +1609,24 @@ mod tests {
     )]
     #[case(
         "#invalid-name: this is an example dependency",
-        Err(Error::RegexDoesNotMatch {
-            value: "#invalid-name".to_string(),
-            regex_type: "pkgname".to_string(),
-            regex: crate::name::NAME_REGEX.to_string(),
+        Err(Error::InvalidRelation {
+            input: "#invalid-name".to_string(),
+            part: RelationPart::Name,
+            source: Some(Box::new(Error::InvalidName {
+                name: "#invalid-name".to_string(),
+                rule: NameRule::DisallowedCharacter('#'),
+            })),
         }),
     )]
     #[case(
         ": no_name_colon",
-        Err(Error::RegexDoesNotMatch {
-            value: "".to_string(),
-            regex_type: "pkgname".to_string(),
-            regex: crate::name::NAME_REGEX.to_string(),
+        Err(Error::InvalidRelation {
+            input: "".to_string(),
+            part: RelationPart::Name,
+            source: Some(Box::new(Error::InvalidName {
+                name: "".to_string(),
+                rule: NameRule::Empty,
+            })),
         }),
     )]
     // versioned optional dependencies
@@ -1141,6 +1669,44 @@ mod tests {
             description: Some("required by extension-wiki-publisher and extension-nlpsolver".to_string()),
         }),
     )]
+    #[case(
+        "python>=3.10: for GUI",
+        Ok(OptionalDependency {
+            package_relation: PackageRelation {
+                name: Name::new("python").unwrap(),
+                version_requirement: Some(VersionRequirement {
+                    comparison: VersionComparison::GreaterOrEqual,
+                    version: "3.10".parse().unwrap(),
+                }),
+            },
+            description: Some("for GUI".to_string()),
+        }),
+    )]
+    #[case(
+        "python>=3.10",
+        Ok(OptionalDependency {
+            package_relation: PackageRelation {
+                name: Name::new("python").unwrap(),
+                version_requirement: Some(VersionRequirement {
+                    comparison: VersionComparison::GreaterOrEqual,
+                    version: "3.10".parse().unwrap(),
+                }),
+            },
+            description: None,
+        }),
+    )]
+    #[case(
+        "python>=: for GUI",
+        Err(Error::InvalidRelation {
+            input: "python>=".to_string(),
+            part: RelationPart::Version,
+            source: Version::from_str("").err().map(Box::new),
+        }),
+    )]
+    #[case(
+        "python: for GUI\nand more",
+        Err(Error::ValueContainsInvalidChars { invalid_char: '\n' }),
+    )]
     fn opt_depend_from_string(
         #[case] input: &str,
         #[case] expected_result: Result<OptionalDependency, Error>,
@@ -1149,6 +1715,405 @@ mod tests {
         assert_eq!(expected_result, opt_depend_result);
     }
 
+    /// Ensure that an unversioned relation is satisfied by any version of a package.
+    #[rstest]
+    #[case("1.0")]
+    #[case("2.0")]
+    #[case("0.1")]
+    #[case("1:0.1-1")]
+    fn unversioned_relation_satisfied_by_any_version(
+        #[case] version: &str,
+    ) -> testresult::TestResult<()> {
+        let relation = PackageRelation::from_str("foo")?;
+        let version = Version::from_str(version)?;
+
+        assert!(relation.satisfied_by(&Name::new("foo")?, &version));
+        assert!(!relation.satisfied_by(&Name::new("bar")?, &version));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("example")]
+    #[case("example>=1.0")]
+    #[case("example=1:1.0-1")]
+    fn package_relation_serde_roundtrip(#[case] relation: &str) -> testresult::TestResult<()> {
+        let relation = PackageRelation::from_str(relation)?;
+        let json = serde_json::to_string(&relation)?;
+        assert_eq!(serde_json::from_str::<PackageRelation>(&json)?, relation);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::without_description("python", "python", None)]
+    #[case::with_description(
+        "python: for the scripting interface",
+        "python",
+        Some("for the scripting interface")
+    )]
+    #[case::colon_in_description(
+        "python>=3: for scripts: foo and bar",
+        "python>=3",
+        Some("for scripts: foo and bar")
+    )]
+    fn opt_depend_shapes(
+        #[case] input: &str,
+        #[case] relation: &str,
+        #[case] description: Option<&str>,
+    ) -> testresult::TestResult<()> {
+        let opt_depend = OptionalDependency::from_str(input)?;
+        assert_eq!(
+            opt_depend.package_relation(),
+            &PackageRelation::from_str(relation)?
+        );
+        assert_eq!(opt_depend.description(), description);
+        assert_eq!(opt_depend.to_string(), input);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("example")]
+    #[case("python>=3.10: for GUI")]
+    fn opt_depend_serde_roundtrip(#[case] opt_depend: &str) -> testresult::TestResult<()> {
+        let opt_depend = OptionalDependency::from_str(opt_depend)?;
+        let json = serde_json::to_string(&opt_depend)?;
+        assert_eq!(
+            serde_json::from_str::<OptionalDependency>(&json)?,
+            opt_depend
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[], "")]
+    #[case(&["example"], "example")]
+    #[case(
+        &["zlib", "glibc>=2.40", "bash", "glibc>=2.40", "glibc", "glibc<=2.41", "bash"],
+        "bash\nglibc\nglibc>=2.40\nglibc<=2.41\nzlib",
+    )]
+    fn package_relations_to_block(
+        #[case] relations: &[&str],
+        #[case] expected: &str,
+    ) -> testresult::TestResult<()> {
+        let relations = relations
+            .iter()
+            .map(|relation| PackageRelation::from_str(relation))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(relations_to_block(&relations), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&[], &[])]
+    #[case(&["glibc>=2.40", "bash", "glibc<3", "bash"], &["bash", "glibc"])]
+    fn package_relation_names(
+        #[case] relations: &[&str],
+        #[case] names: &[&str],
+    ) -> testresult::TestResult<()> {
+        let relations = relations
+            .iter()
+            .map(|relation| PackageRelation::from_str(relation))
+            .collect::<Result<Vec<_>, _>>()?;
+        let names = names
+            .iter()
+            .map(|name| Name::new(name))
+            .collect::<Result<HashSet<_>, _>>()?;
+        assert_eq!(relation_names(&relations), names);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::plain("libfoo", "libfoo", None)]
+    #[case::with_epoch(
+        "libfoo>=1:1.2.3-1",
+        "libfoo",
+        Some(VersionRequirement {
+            comparison: VersionComparison::GreaterOrEqual,
+            version: Version::from_str("1:1.2.3-1").unwrap(),
+        })
+    )]
+    #[case::hyphenated_name(
+        "lib-foo-bar<=1.2.3-1",
+        "lib-foo-bar",
+        Some(VersionRequirement {
+            comparison: VersionComparison::LessOrEqual,
+            version: Version::from_str("1.2.3-1").unwrap(),
+        })
+    )]
+    fn package_relation_parse_and_display(
+        #[case] input: &str,
+        #[case] name: &str,
+        #[case] version_requirement: Option<VersionRequirement>,
+    ) -> testresult::TestResult<()> {
+        let relation = PackageRelation::from_str(input)?;
+        assert_eq!(
+            relation,
+            PackageRelation::new(Name::new(name)?, version_requirement)
+        );
+        assert_eq!(relation.to_string(), input);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("lib-foo", "lib-foo", "0.1", true)]
+    #[case("lib-foo>=1:1.0", "lib-foo", "1:1.0", true)]
+    #[case("lib-foo>=1:1.0", "lib-foo", "2.0", false)]
+    #[case("lib-foo<2.0-1", "lib-foo", "1.9-1", true)]
+    #[case("lib-foo<2.0-1", "lib-foo-bar", "1.9-1", false)]
+    fn package_relation_satisfied_by(
+        #[case] relation: &str,
+        #[case] name: &str,
+        #[case] version: &str,
+        #[case] satisfied: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            PackageRelation::from_str(relation)?
+                .satisfied_by(&Name::new(name)?, &Version::from_str(version)?),
+            satisfied
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unversioned_provide_versioned_requirement("sh>=5", &["sh"], false)]
+    #[case::versioned_provide_versioned_requirement("sh>=5", &["sh=5.2"], true)]
+    #[case::versioned_provide_unsatisfied_requirement("sh>=6", &["sh=5.2"], false)]
+    #[case::non_exact_provide("sh>=5", &["sh>=5.2"], false)]
+    #[case::unversioned_provide_unversioned_requirement("sh", &["sh"], true)]
+    #[case::versioned_provide_unversioned_requirement("sh", &["sh=5.2"], true)]
+    #[case::other_name("sh", &["zsh=5.9", "bash"], false)]
+    #[case::several_provides("sh=5.2-1", &["zsh", "sh=5.2-1"], true)]
+    #[case::no_provides("sh", &[], false)]
+    fn package_relation_matches_provides(
+        #[case] relation: &str,
+        #[case] provides: &[&str],
+        #[case] matches: bool,
+    ) -> testresult::TestResult<()> {
+        let provides = provides
+            .iter()
+            .map(|provide| PackageRelation::from_str(provide))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            PackageRelation::from_str(relation)?.matches_provides(&provides),
+            matches
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(
+        "libcrypto.so=3-64",
+        Some(SonameV1::Explicit {
+            name: SharedObjectName::new("libcrypto.so").unwrap(),
+            version: PackageVersion::new("3".to_string()).unwrap(),
+            architecture: ElfArchitectureFormat::Bit64,
+        })
+    )]
+    #[case(
+        "lib.so=1-32",
+        Some(SonameV1::Explicit {
+            name: SharedObjectName::new("lib.so").unwrap(),
+            version: PackageVersion::new("1".to_string()).unwrap(),
+            architecture: ElfArchitectureFormat::Bit32,
+        })
+    )]
+    #[case(
+        "libexample.so=libexample.so-64",
+        Some(SonameV1::Unversioned {
+            name: SharedObjectName::new("libexample.so").unwrap(),
+            soname: SharedObjectName::new("libexample.so").unwrap(),
+            architecture: ElfArchitectureFormat::Bit64,
+        })
+    )]
+    #[case("glibc>=2.38", None)]
+    #[case("glibc=2.38-1", None)]
+    #[case("example=3-64", None)]
+    #[case("libcrypto.so", None)]
+    #[case("libcrypto.so>=3-64", None)]
+    #[case("libcrypto.so=3-1", None)]
+    fn package_relation_soname(
+        #[case] relation: &str,
+        #[case] soname: Option<SonameV1>,
+    ) -> testresult::TestResult<()> {
+        let relation = PackageRelation::from_str(relation)?;
+        assert_eq!(relation.is_soname(), soname.is_some());
+        if let Some(soname) = relation.soname() {
+            assert_eq!(soname.to_string(), relation.to_string());
+        }
+        assert_eq!(relation.soname(), soname);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("python*", "python-requests", "1.0.0-1", true)]
+    #[case("python*", "python", "1.0.0-1", true)]
+    #[case("python*", "ruby", "1.0.0-1", false)]
+    #[case("*-requests", "python-requests", "1.0.0-1", true)]
+    #[case("python?", "python3", "1.0.0-1", true)]
+    #[case("python?", "python", "1.0.0-1", false)]
+    #[case("python?", "python-requests", "1.0.0-1", false)]
+    #[case("*a*b", "xaxxb", "1.0.0-1", true)]
+    #[case("*a*b", "xaxxbc", "1.0.0-1", false)]
+    #[case("ruby", "ruby", "1.0.0-1", true)]
+    #[case("python*>=2", "python-requests", "2.32.3-1", true)]
+    #[case("python*>=2", "python-requests", "1.0.0-1", false)]
+    #[case("python*=2.32.3", "python-requests", "2.32.3-1", true)]
+    #[case("python*<2", "ruby", "1.0.0-1", false)]
+    fn relation_glob(
+        #[case] pattern: &str,
+        #[case] name: &str,
+        #[case] version: &str,
+        #[case] matches: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            relation_glob_matches(pattern, &Name::new(name)?, &Version::from_str(version)?)?,
+            matches
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("-python*")]
+    #[case("python *")]
+    #[case("python*>")]
+    fn relation_glob_invalid(#[case] pattern: &str) -> testresult::TestResult<()> {
+        assert!(
+            relation_glob_matches(pattern, &Name::new("python")?, &Version::from_str("1.0.0")?)
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("base", Ok(()))]
+    #[case("base-devel", Ok(()))]
+    #[case("xorg-apps", Ok(()))]
+    #[case(
+        "-base",
+        Err(Error::InvalidName { name: "-base".to_string(), rule: NameRule::LeadingCharacter('-') })
+    )]
+    #[case(
+        "my group",
+        Err(Error::InvalidName { name: "my group".to_string(), rule: NameRule::DisallowedCharacter(' ') })
+    )]
+    #[case("", Err(Error::InvalidName { name: "".to_string(), rule: NameRule::Empty }))]
+    fn group_validation(#[case] group: &str, #[case] result: Result<(), Error>) {
+        assert_eq!(validate_group(group), result);
+    }
+
+    #[test]
+    fn group_membership() -> testresult::TestResult<()> {
+        let membership = GroupMembership::new(
+            Name::new("gcc")?,
+            vec!["base-devel".to_string(), "toolchain".to_string()],
+        )?;
+        assert_eq!(membership.name(), &Name::new("gcc")?);
+        assert!(membership.contains("base-devel"));
+        assert!(membership.contains("toolchain"));
+        assert!(!membership.contains("base"));
+
+        assert!(
+            GroupMembership::new(
+                Name::new("gcc")?,
+                vec!["base-devel".to_string(), "+toolchain".to_string()],
+            )
+            .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn package_relation_hash_set() -> testresult::TestResult<()> {
+        let relations = ["foo>=1.01", "foo>=1.1", "foo>=1.1-1", "foo", "bar>=1.1"]
+            .iter()
+            .map(|relation| PackageRelation::from_str(relation))
+            .collect::<Result<HashSet<_>, _>>()?;
+        assert_eq!(relations.len(), 4);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::invalid_name("-foo>=1.0", RelationPart::Name)]
+    #[case::invalid_name_without_version("foo!", RelationPart::Name)]
+    #[case::invalid_operator("foo=>1.0", RelationPart::Operator)]
+    #[case::duplicate_operator("foo<<1.0", RelationPart::Operator)]
+    #[case::invalid_version("foo>=", RelationPart::Version)]
+    #[case::invalid_version_chars("foo>=1.0:bad", RelationPart::Version)]
+    fn invalid_package_relation(#[case] input: &str, #[case] part: RelationPart) {
+        let Err(Error::InvalidRelation {
+            input: error_input,
+            part: error_part,
+            source,
+        }) = PackageRelation::from_str(input)
+        else {
+            panic!("Parsing {input} did not fail with an Error::InvalidRelation");
+        };
+        assert_eq!(error_input, input);
+        assert_eq!(error_part, part);
+        // Only an invalid operator is detected without the help of another parser.
+        assert_eq!(source.is_none(), part == RelationPart::Operator);
+    }
+
+    #[rstest]
+    #[case::by_name("example", true)]
+    #[case::by_name_and_version("example>=1.0", true)]
+    #[case::version_not_satisfied("example>=2.0", false)]
+    #[case::by_provide("virtual", true)]
+    #[case::by_versioned_provide("versioned>=1", true)]
+    #[case::versioned_provide_not_satisfied("versioned<1", false)]
+    #[case::unversioned_provide_with_requirement("virtual>=1", false)]
+    #[case::unknown("other", false)]
+    fn dependency_satisfied(
+        #[case] dependency: &str,
+        #[case] satisfied: bool,
+    ) -> testresult::TestResult<()> {
+        let provides = [
+            PackageRelation::from_str("virtual")?,
+            PackageRelation::from_str("versioned=1.5")?,
+        ];
+        let candidates = [(
+            Name::new("example")?,
+            Version::from_str("1.0-1")?,
+            provides.as_slice(),
+        )];
+        assert_eq!(
+            is_dependency_satisfied(&PackageRelation::from_str(dependency)?, &candidates),
+            satisfied
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn opt_depend_sort_and_dedup() -> testresult::TestResult<()> {
+        let mut opt_depends = [
+            "python>=3: For Python bindings",
+            "elfutils=0.192: for translations",
+            "python: For Python bindings",
+            "python>=3: For Python bindings",
+            "elfutils",
+            "python>=2: For Python bindings",
+            "elfutils: for translations",
+        ]
+        .into_iter()
+        .map(OptionalDependency::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let expected = [
+            "elfutils",
+            "elfutils: for translations",
+            "elfutils=0.192: for translations",
+            "python: For Python bindings",
+            "python>=2: For Python bindings",
+            "python>=3: For Python bindings",
+        ]
+        .into_iter()
+        .map(OptionalDependency::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        OptionalDependency::sort_and_dedup(&mut opt_depends);
+        assert_eq!(expected, opt_depends);
+        Ok(())
+    }
+
     #[rstest]
     #[case("example.so", SonameV1::Basic("example.so".parse().unwrap()))]
     #[case("example.so=1.0.0-64", SonameV1::Explicit {
@@ -1156,6 +2121,16 @@ mod tests {
         version: "1.0.0".parse().unwrap(),
         architecture: ElfArchitectureFormat::Bit64,
     })]
+    #[case("libfoo.so=1-64", SonameV1::Explicit {
+        name: "libfoo.so".parse().unwrap(),
+        version: "1".parse().unwrap(),
+        architecture: ElfArchitectureFormat::Bit64,
+    })]
+    #[case("libfoo.so=1.2-32", SonameV1::Explicit {
+        name: "libfoo.so".parse().unwrap(),
+        version: "1.2".parse().unwrap(),
+        architecture: ElfArchitectureFormat::Bit32,
+    })]
     fn sonamev1_from_string(
         #[case] input: &str,
         #[case] expected_result: SonameV1,
@@ -1,3 +1,7 @@
+use std::num::IntErrorKind;
+
+use crate::Error;
+
 /// Compressed size of a file (in bytes)
 ///
 /// This is a type alias for [`u64`].
@@ -28,3 +32,258 @@ pub type CompressedSize = u64;
 /// assert!(InstalledSize::from_str("-1").is_err());
 /// ```
 pub type InstalledSize = u64;
+
+/// Returns the ratio of an [`InstalledSize`] to a [`CompressedSize`]
+///
+/// The ratio describes how much larger the installed data is compared to its compressed
+/// representation (e.g. a download).
+/// Returns [`None`] if `compressed` is `0`, as no meaningful ratio can be calculated.
+///
+/// ## Examples
+/// ```
+/// use alpm_types::compression_ratio;
+///
+/// assert_eq!(compression_ratio(&1024, &4096), Some(4.0));
+/// assert_eq!(compression_ratio(&0, &4096), None);
+/// ```
+pub fn compression_ratio(compressed: &CompressedSize, installed: &InstalledSize) -> Option<f64> {
+    if *compressed == 0 {
+        return None;
+    }
+
+    Some(*installed as f64 / *compressed as f64)
+}
+
+/// The binary units used by [`human_readable_size`] and [`size_from_human_readable`]
+const SIZE_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+/// Returns a human-readable representation of a size in bytes
+///
+/// The size is expressed in binary (1024-based) units up to `TiB`, using at most two decimal
+/// places. Sizes below 1024 bytes are expressed in bytes.
+/// Use this with [`InstalledSize`] or [`CompressedSize`], whose [`Display`](std::fmt::Display)
+/// implementation always yields the raw number of bytes.
+///
+/// ## Examples
+/// ```
+/// use alpm_types::human_readable_size;
+///
+/// assert_eq!(human_readable_size(1023), "1023 B");
+/// assert_eq!(human_readable_size(1536), "1.5 KiB");
+/// assert_eq!(human_readable_size(10 * 1024 * 1024), "10 MiB");
+/// ```
+pub fn human_readable_size(size: u64) -> String {
+    let mut value = size as f64;
+    let mut unit = 0;
+    // Round to two decimal places before deciding on the unit, so that e.g. 1048575 bytes are
+    // expressed as "1 MiB" instead of "1024 KiB".
+    while unit < SIZE_UNITS.len() - 1 && (value * 100.0).round() / 100.0 >= 1024.0 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    let value = format!("{value:.2}");
+    let value = value.trim_end_matches('0').trim_end_matches('.');
+    format!("{value} {}", SIZE_UNITS[unit])
+}
+
+/// Parses a size in bytes from a human-readable representation
+///
+/// Accepts a (possibly fractional) number, optionally followed by one of the binary units `B`,
+/// `KiB`, `MiB`, `GiB` or `TiB`. The number and unit may be separated by whitespace.
+/// Fractional bytes are rounded to the nearest integer.
+///
+/// ## Errors
+///
+/// Returns an [`Error::InvalidSize`] if the number is invalid, the unit is unknown, or the size
+/// does not fit into a [`u64`].
+///
+/// ## Examples
+/// ```
+/// use alpm_types::size_from_human_readable;
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// assert_eq!(size_from_human_readable("1.5 KiB")?, 1536);
+/// assert_eq!(size_from_human_readable("10MiB")?, 10 * 1024 * 1024);
+/// assert_eq!(size_from_human_readable("42")?, 42);
+/// assert!(size_from_human_readable("1.5 KB").is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn size_from_human_readable(s: &str) -> Result<u64, Error> {
+    let invalid = || Error::InvalidSize {
+        input: s.to_string(),
+    };
+
+    let unit_start = s
+        .find(|char: char| !(char.is_ascii_digit() || char == '.'))
+        .unwrap_or(s.len());
+    let (number, unit) = s.split_at(unit_start);
+    let unit = unit.trim_start();
+
+    let exponent = if unit.is_empty() {
+        0
+    } else {
+        SIZE_UNITS
+            .iter()
+            .position(|known| *known == unit)
+            .ok_or_else(invalid)?
+    };
+    // Bare numbers are byte counts and must be integers.
+    if exponent == 0 {
+        return number.parse().map_err(|_| invalid());
+    }
+
+    let multiplier = 1024_u64.pow(exponent as u32);
+    let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+    let integer: u64 = if integer.is_empty() {
+        0
+    } else {
+        integer.parse().map_err(|_| invalid())?
+    };
+    // The fractional share of the size is smaller than the multiplier, which is well within the
+    // precision of an f64.
+    let fraction: f64 = format!("0.{fraction}").parse().map_err(|_| invalid())?;
+
+    integer
+        .checked_mul(multiplier)
+        .and_then(|size| size.checked_add((fraction * multiplier as f64).round() as u64))
+        .ok_or_else(invalid)
+}
+
+/// Returns the sum of sizes in bytes, or an error if it overflows
+///
+/// As [`InstalledSize`] and [`CompressedSize`] are type aliases for [`u64`], they already support
+/// arithmetic, comparison and [`Iterator::sum`]. However, the latter panics on overflow in debug
+/// builds and wraps around in release builds.
+/// Use this function to safely sum up sizes of untrusted data.
+///
+/// ## Errors
+///
+/// Returns an [`Error::InvalidInteger`] with [`IntErrorKind::PosOverflow`] if the sum does not
+/// fit into a [`u64`].
+///
+/// ## Examples
+/// ```
+/// use alpm_types::{InstalledSize, checked_size_sum};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let sizes: [InstalledSize; 3] = [1024, 2048, 4096];
+/// assert_eq!(checked_size_sum(sizes)?, 7168);
+/// assert_eq!(sizes.iter().max(), Some(&4096));
+/// assert!(checked_size_sum([u64::MAX, 1]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn checked_size_sum(sizes: impl IntoIterator<Item = u64>) -> Result<u64, Error> {
+    sizes.into_iter().try_fold(0_u64, |total, size| {
+        total.checked_add(size).ok_or(Error::InvalidInteger {
+            kind: IntErrorKind::PosOverflow,
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1024, 4096, Some(4.0))]
+    #[case(4096, 1024, Some(0.25))]
+    #[case(1, 0, Some(0.0))]
+    #[case(0, 0, None)]
+    #[case(0, 4096, None)]
+    fn size_compression_ratio(
+        #[case] compressed: CompressedSize,
+        #[case] installed: InstalledSize,
+        #[case] expected: Option<f64>,
+    ) {
+        assert_eq!(compression_ratio(&compressed, &installed), expected);
+    }
+
+    #[test]
+    fn installed_size_sum() -> testresult::TestResult<()> {
+        let sizes: Vec<InstalledSize> = vec![181849963, 1024, 0, 42];
+        assert_eq!(sizes.iter().sum::<InstalledSize>(), 181851029);
+        assert_eq!(checked_size_sum(sizes.iter().copied())?, 181851029);
+        assert_eq!(sizes.iter().max(), Some(&181849963));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::max_plus_one(vec![u64::MAX, 1])]
+    #[case::max_twice(vec![u64::MAX, 0, u64::MAX])]
+    fn checked_size_sum_overflow(#[case] sizes: Vec<CompressedSize>) {
+        assert_eq!(
+            checked_size_sum(sizes),
+            Err(Error::InvalidInteger {
+                kind: IntErrorKind::PosOverflow
+            })
+        );
+    }
+
+    #[test]
+    fn checked_size_sum_max() -> testresult::TestResult<()> {
+        assert_eq!(checked_size_sum([u64::MAX - 1, 1])?, u64::MAX);
+        assert_eq!(checked_size_sum([])?, 0);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(0, "0 B")]
+    #[case(1023, "1023 B")]
+    #[case(1024, "1 KiB")]
+    #[case(1025, "1 KiB")]
+    #[case(1536, "1.5 KiB")]
+    #[case(1100, "1.07 KiB")]
+    #[case(1048570, "1023.99 KiB")]
+    #[case(1048575, "1 MiB")]
+    #[case(1048576, "1 MiB")]
+    #[case(5 * 1024 * 1024 * 1024, "5 GiB")]
+    #[case(2048 * 1024 * 1024 * 1024 * 1024, "2048 TiB")]
+    fn size_human_readable(#[case] size: u64, #[case] expected: &str) {
+        assert_eq!(human_readable_size(size), expected);
+    }
+
+    #[rstest]
+    #[case("0", Ok(0))]
+    #[case("1023", Ok(1023))]
+    #[case("1023 B", Ok(1023))]
+    #[case("1 KiB", Ok(1024))]
+    #[case("1.5 KiB", Ok(1536))]
+    #[case("1.07 KiB", Ok(1096))]
+    #[case("10MiB", Ok(10 * 1024 * 1024))]
+    #[case("2 GiB", Ok(2 * 1024 * 1024 * 1024))]
+    #[case("1 TiB", Ok(1024 * 1024 * 1024 * 1024))]
+    #[case(".5 KiB", Ok(512))]
+    #[case("9007199254740993", Ok(9007199254740993))]
+    #[case("18446744073709551615", Ok(u64::MAX))]
+    #[case("16777215.99999999 TiB", Ok(18446744073709540621))]
+    #[case(
+        "18446744073709551616",
+        Err(Error::InvalidSize { input: "18446744073709551616".to_string() })
+    )]
+    #[case(
+        "16777216 TiB",
+        Err(Error::InvalidSize { input: "16777216 TiB".to_string() })
+    )]
+    #[case("1.5", Err(Error::InvalidSize { input: "1.5".to_string() }))]
+    #[case(". KiB", Err(Error::InvalidSize { input: ". KiB".to_string() }))]
+    #[case("1.2.3 KiB", Err(Error::InvalidSize { input: "1.2.3 KiB".to_string() }))]
+    #[case("1.5 KB", Err(Error::InvalidSize { input: "1.5 KB".to_string() }))]
+    #[case("KiB", Err(Error::InvalidSize { input: "KiB".to_string() }))]
+    #[case("-1 KiB", Err(Error::InvalidSize { input: "-1 KiB".to_string() }))]
+    #[case("", Err(Error::InvalidSize { input: "".to_string() }))]
+    #[case(
+        "99999999 TiB",
+        Err(Error::InvalidSize { input: "99999999 TiB".to_string() })
+    )]
+    fn size_parse_human_readable(#[case] input: &str, #[case] expected: Result<u64, Error>) {
+        assert_eq!(size_from_human_readable(input), expected);
+    }
+}
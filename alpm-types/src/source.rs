@@ -1,4 +1,5 @@
 use std::{
+    collections::HashSet,
     fmt::{Display, Formatter},
     path::PathBuf,
     str::FromStr,
@@ -6,7 +7,32 @@ use std::{
 
 use serde::Serialize;
 
-use crate::{Error, SourceUrl};
+use crate::{
+    ChecksumAlgorithm,
+    ChecksumPolicy,
+    Error,
+    Sha256Checksum,
+    SourceFragment,
+    SourceUrl,
+    VcsProtocol,
+    url::{BzrFragment, FossilFragment, GitFragment, HgFragment, SvnFragment, VcsInfo},
+};
+
+/// The file name extensions of archives that are extracted by makepkg
+const ARCHIVE_EXTENSIONS: &[&str] = &[
+    ".tar",
+    ".tar.bz2",
+    ".tar.gz",
+    ".tar.lz",
+    ".tar.lz4",
+    ".tar.lzma",
+    ".tar.xz",
+    ".tar.zst",
+    ".tbz2",
+    ".tgz",
+    ".txz",
+    ".zip",
+];
 
 /// Represents the location that a source file should be retrieved from
 ///
@@ -40,6 +66,385 @@ impl Source {
             Self::File { filename, .. } | Self::SourceUrl { filename, .. } => filename.as_ref(),
         }
     }
+
+    /// Returns the [`VcsProtocol`] of the source, if it is a VCS source.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{Source, VcsProtocol};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let source = Source::from_str("git+https://example.com/foo.git#tag=v1.0.0")?;
+    /// assert_eq!(source.vcs_protocol(), Some(VcsProtocol::Git));
+    ///
+    /// let source = Source::from_str("https://example.com/foo-1.0.0.tar.gz")?;
+    /// assert_eq!(source.vcs_protocol(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn vcs_protocol(&self) -> Option<VcsProtocol> {
+        self.vcs_info().map(VcsInfo::protocol)
+    }
+
+    /// Returns the [`SourceFragment`] of the source, if it is a VCS source with a fragment.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{Source, SourceFragment};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let source = Source::from_str("git+https://example.com/foo.git#tag=v1.0.0")?;
+    /// assert_eq!(
+    ///     source.fragment(),
+    ///     Some(SourceFragment::Tag("v1.0.0".to_string()))
+    /// );
+    ///
+    /// let source = Source::from_str("git+https://example.com/foo.git")?;
+    /// assert_eq!(source.fragment(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fragment(&self) -> Option<SourceFragment> {
+        self.vcs_info().and_then(VcsInfo::fragment)
+    }
+
+    /// Returns the [`VcsInfo`] of the source, if it is a VCS source.
+    fn vcs_info(&self) -> Option<&VcsInfo> {
+        match self {
+            Self::File { .. } => None,
+            Self::SourceUrl { source_url, .. } => source_url.vcs_info.as_ref(),
+        }
+    }
+
+    /// Returns `true` if the source always refers to the same data.
+    ///
+    /// Local files and URLs without a VCS are considered reproducible, as their integrity is
+    /// ensured using checksums.
+    /// VCS sources are only considered reproducible, if they are pinned to a specific commit or
+    /// revision.
+    /// VCS sources without a fragment or those referring to a branch or tag are not reproducible,
+    /// as branches and tags may be moved.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Source;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert!(Source::from_str("https://example.com/foo-1.0.0.tar.gz")?.is_reproducible());
+    /// assert!(Source::from_str("git+https://example.com/foo.git#commit=deadbeef")?.is_reproducible());
+    /// assert!(!Source::from_str("git+https://example.com/foo.git#tag=v1.0.0")?.is_reproducible());
+    /// assert!(!Source::from_str("git+https://example.com/foo.git")?.is_reproducible());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_reproducible(&self) -> bool {
+        let Self::SourceUrl { source_url, .. } = self else {
+            return true;
+        };
+        let Some(vcs_info) = &source_url.vcs_info else {
+            return true;
+        };
+
+        match vcs_info {
+            VcsInfo::Bzr { fragment } => matches!(fragment, Some(BzrFragment::Revision(_))),
+            VcsInfo::Fossil { fragment } => matches!(fragment, Some(FossilFragment::Commit(_))),
+            VcsInfo::Git { fragment, .. } => matches!(fragment, Some(GitFragment::Commit(_))),
+            VcsInfo::Hg { fragment } => matches!(fragment, Some(HgFragment::Revision(_))),
+            VcsInfo::Svn { fragment } => matches!(fragment, Some(SvnFragment::Revision(_))),
+        }
+    }
+
+    /// Returns `true` if the source is an archive that is extracted by makepkg.
+    ///
+    /// The [effective file name] of the source is checked for one of the following extensions:
+    /// `.tar`, `.tar.bz2`, `.tar.gz`, `.tar.lz`, `.tar.lz4`, `.tar.lzma`, `.tar.xz`, `.tar.zst`,
+    /// `.tbz2`, `.tgz`, `.txz` and `.zip`.
+    /// VCS sources are never considered archives.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Source;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert!(Source::from_str("https://example.com/foo-1.0.0.tar.gz")?.is_archive());
+    /// assert!(Source::from_str("foo.zip::https://example.com/download")?.is_archive());
+    /// assert!(!Source::from_str("fix-build.patch")?.is_archive());
+    /// assert!(!Source::from_str("git+https://example.com/foo.tar.gz")?.is_archive());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [effective file name]: Source::effective_filename
+    pub fn is_archive(&self) -> bool {
+        if matches!(self, Self::SourceUrl { source_url, .. } if source_url.vcs_info.is_some()) {
+            return false;
+        }
+
+        let filename = self.effective_filename();
+        let filename = filename.to_string_lossy();
+        ARCHIVE_EXTENSIONS
+            .iter()
+            .any(|extension| filename.ends_with(extension))
+    }
+
+    /// Returns a short, stable identifier for the content the source refers to.
+    ///
+    /// The identifier consists of the first 16 characters of the hex representation of a
+    /// [`Sha256Checksum`] over the canonical representation of the source's location:
+    ///
+    /// - For local files the location is used.
+    /// - For URLs the canonical URL is used, which for VCS sources includes the fragment (e.g.
+    ///   `#commit=...`) the source is pinned to.
+    ///
+    /// The destination file name of a source is not taken into account, which means that sources
+    /// retrieving the same data under different file names share the same identifier.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Source;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let source = Source::from_str("git+https://example.com/foo.git#commit=deadbeef")?;
+    /// let renamed = Source::from_str("bar::git+https://example.com/foo.git#commit=deadbeef")?;
+    /// let repinned = Source::from_str("git+https://example.com/foo.git#commit=c0ffee")?;
+    ///
+    /// assert_eq!(source.content_id().len(), 16);
+    /// assert_eq!(source.content_id(), renamed.content_id());
+    /// assert_ne!(source.content_id(), repinned.content_id());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn content_id(&self) -> String {
+        let location = match self {
+            Self::File { location, .. } => format!("file:{}", location.display()),
+            Self::SourceUrl { source_url, .. } => source_url.to_string(),
+        };
+
+        let mut content_id = Sha256Checksum::calculate_from(location).to_string();
+        content_id.truncate(16);
+        content_id
+    }
+
+    /// Returns the file name that the source is stored as after retrieval.
+    ///
+    /// If a destination file name is set, it is used.
+    /// Otherwise the file name is derived from the location of the source, following the behavior
+    /// of makepkg:
+    ///
+    /// - For local files the file name is used as is.
+    /// - For VCS sources the last path segment of the URL is used. For git, a `.git` suffix is
+    ///   removed and for fossil a `.fossil` suffix is added.
+    /// - For all other URLs everything after the last `/` is used.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::{path::PathBuf, str::FromStr};
+    ///
+    /// use alpm_types::Source;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let source = Source::from_str("https://example.com/foo-1.0.0.tar.gz")?;
+    /// assert_eq!(
+    ///     source.effective_filename(),
+    ///     PathBuf::from("foo-1.0.0.tar.gz")
+    /// );
+    ///
+    /// let source = Source::from_str("git+https://example.com/project.git#tag=v1.0.0")?;
+    /// assert_eq!(source.effective_filename(), PathBuf::from("project"));
+    ///
+    /// let source = Source::from_str("bar.tar.gz::https://example.com/download")?;
+    /// assert_eq!(source.effective_filename(), PathBuf::from("bar.tar.gz"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn effective_filename(&self) -> PathBuf {
+        if let Some(filename) = self.filename() {
+            return filename.clone();
+        }
+
+        match self {
+            Self::File { location, .. } => location.clone(),
+            Self::SourceUrl { source_url, .. } => {
+                let Some(vcs_info) = &source_url.vcs_info else {
+                    let url = source_url.url.as_str();
+                    return PathBuf::from(url.rsplit('/').next().unwrap_or(url));
+                };
+
+                let path = source_url.url.inner().path().trim_end_matches('/');
+                let name = path.rsplit('/').next().unwrap_or(path);
+                match vcs_info {
+                    VcsInfo::Fossil { .. } => PathBuf::from(format!("{name}.fossil")),
+                    VcsInfo::Git { .. } => {
+                        PathBuf::from(name.split_once(".git").map_or(name, |(name, _)| name))
+                    }
+                    VcsInfo::Bzr { .. } | VcsInfo::Hg { .. } | VcsInfo::Svn { .. } => {
+                        PathBuf::from(name)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the file name that the source is stored as after retrieval, if one can be derived.
+    ///
+    /// This is the [`Source::effective_filename`] as a [`String`].
+    /// As with makepkg, query parameters of URLs that are not VCS sources are part of the file
+    /// name.
+    /// Returns [`None`] if no file name can be derived (e.g. for a URL ending in `/`) or if the
+    /// file name is not valid unicode.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Source;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let source = Source::from_str("git+https://example.com/foo.git")?;
+    /// assert_eq!(source.file_name(), Some("foo".to_string()));
+    ///
+    /// let source = Source::from_str("https://example.com/")?;
+    /// assert_eq!(source.file_name(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn file_name(&self) -> Option<String> {
+        self.effective_filename()
+            .to_str()
+            .filter(|file_name| !file_name.is_empty())
+            .map(ToString::to_string)
+    }
+}
+
+/// Validates that no two [`Source`]s are stored using the same file name.
+///
+/// Sources that share the same [effective file name] would overwrite each other upon retrieval.
+///
+/// ## Errors
+///
+/// Returns an [`Error::DuplicateSourceFilename`] for the first file name that is used by more
+/// than one source.
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{Source, validate_unique_source_filenames};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let sources = [
+///     Source::from_str("https://example.com/v1/foo.tar.gz")?,
+///     Source::from_str("https://example.org/v2/foo.tar.gz")?,
+/// ];
+/// assert!(validate_unique_source_filenames(&sources).is_err());
+///
+/// let sources = [
+///     Source::from_str("https://example.com/v1/foo.tar.gz")?,
+///     Source::from_str("foo-v2.tar.gz::https://example.org/v2/foo.tar.gz")?,
+/// ];
+/// assert!(validate_unique_source_filenames(&sources).is_ok());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [effective file name]: Source::effective_filename
+pub fn validate_unique_source_filenames(sources: &[Source]) -> Result<(), Error> {
+    let mut filenames = HashSet::new();
+    for source in sources {
+        let filename = source.effective_filename();
+        if filenames.contains(&filename) {
+            return Err(Error::DuplicateSourceFilename { name: filename });
+        }
+        filenames.insert(filename);
+    }
+
+    Ok(())
+}
+
+/// Parses each entry of a source array into a [`Source`]
+///
+/// Contrary to collecting into a single [`Result`], every entry is parsed on its own and the
+/// results are returned in the order of `values`. This allows to report all invalid entries
+/// (and their index) at once.
+///
+/// ## Examples
+///
+/// ```
+/// use alpm_types::parse_source_array;
+///
+/// let sources = parse_source_array(&["foo.patch", "::", "https://example.com/foo.tar.gz"]);
+/// assert_eq!(sources.len(), 3);
+/// assert!(sources[0].is_ok());
+/// assert!(sources[1].is_err());
+/// assert!(sources[2].is_ok());
+/// ```
+pub fn parse_source_array(values: &[&str]) -> Vec<Result<Source, Error>> {
+    values.iter().map(|value| Source::from_str(value)).collect()
+}
+
+/// Returns the [`ChecksumAlgorithm`]s required by a [`ChecksumPolicy`] that are not present
+///
+/// Sources that are retrieved using a VCS are not verified using checksums. Hence, if all
+/// `sources` are VCS sources (or there are no sources), no checksum array is missing.
+///
+/// ## Examples
+///
+/// ```
+/// use std::str::FromStr;
+///
+/// use alpm_types::{ChecksumAlgorithm, ChecksumPolicy, Source, missing_checksum_arrays};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// let policy = ChecksumPolicy::new(vec![ChecksumAlgorithm::Sha256]);
+///
+/// let sources = [Source::from_str("https://example.com/foo-1.0.0.tar.gz")?];
+/// assert_eq!(
+///     missing_checksum_arrays(&sources, &[ChecksumAlgorithm::Md5], &policy),
+///     vec![ChecksumAlgorithm::Sha256]
+/// );
+///
+/// let sources = [Source::from_str("git+https://example.com/foo.git")?];
+/// assert!(missing_checksum_arrays(&sources, &[], &policy).is_empty());
+/// # Ok(())
+/// # }
+/// ```
+pub fn missing_checksum_arrays(
+    sources: &[Source],
+    present: &[ChecksumAlgorithm],
+    policy: &ChecksumPolicy,
+) -> Vec<ChecksumAlgorithm> {
+    let needs_checksums = sources.iter().any(|source| match source {
+        Source::File { .. } => true,
+        Source::SourceUrl { source_url, .. } => source_url.vcs_info.is_none(),
+    });
+    if !needs_checksums {
+        return Vec::new();
+    }
+
+    policy
+        .required()
+        .iter()
+        .filter(|algorithm| !present.contains(algorithm))
+        .copied()
+        .collect()
 }
 
 impl FromStr for Source {
@@ -246,4 +651,265 @@ mod tests {
             assert_eq!(source.to_string(), input);
         }
     }
+
+    #[rstest]
+    #[case("bikeshed_colour.patch", "bikeshed_colour.patch")]
+    #[case("renamed::local", "renamed")]
+    #[case("https://example.com/foo-1.2.3.tar.gz", "foo-1.2.3.tar.gz")]
+    #[case("foo-1.2.3.tar.gz::https://example.com/download", "foo-1.2.3.tar.gz")]
+    #[case(
+        "git+https://example.com/project/repo.git?signed#commit=deadbeef",
+        "repo"
+    )]
+    #[case("git://example.com/project/repo", "repo")]
+    #[case("my-git-repo::git+https://example.com/project/repo.git", "my-git-repo")]
+    #[case("svn+https://example.com/project/trunk/", "trunk")]
+    #[case("fossil+https://example.com/project", "project.fossil")]
+    fn source_effective_filename(
+        #[case] input: &str,
+        #[case] expected: &str,
+    ) -> testresult::TestResult<()> {
+        let source = Source::from_str(input)?;
+        assert_eq!(source.effective_filename(), PathBuf::from(expected));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::renamed(
+        "foo-1.2.3.tar.gz::https://example.com/download",
+        Some("foo-1.2.3.tar.gz")
+    )]
+    #[case::query(
+        "https://example.com/foo-1.2.3.tar.gz?raw=true",
+        Some("foo-1.2.3.tar.gz?raw=true")
+    )]
+    #[case::git("git+https://example.com/foo.git", Some("foo"))]
+    #[case::git_fragment("git+https://example.com/foo.git#tag=v1.0.0", Some("foo"))]
+    #[case::local("foo.patch", Some("foo.patch"))]
+    #[case::no_name("https://example.com/", None)]
+    fn source_file_name(
+        #[case] input: &str,
+        #[case] expected: Option<&str>,
+    ) -> testresult::TestResult<()> {
+        let source = Source::from_str(input)?;
+        assert_eq!(source.file_name().as_deref(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("bikeshed_colour.patch", true)]
+    #[case("https://example.com/foo-1.2.3.tar.gz", true)]
+    #[case("git+https://example.com/repo.git#commit=deadbeef", true)]
+    #[case("git+https://example.com/repo.git?signed#commit=deadbeef", true)]
+    #[case("git+https://example.com/repo.git#tag=v1.0.0", false)]
+    #[case("git+https://example.com/repo.git#branch=main", false)]
+    #[case("git+https://example.com/repo.git", false)]
+    #[case("bzr+https://example.com/repo#revision=42", true)]
+    #[case("bzr+https://example.com/repo", false)]
+    #[case("fossil+https://example.com/repo#commit=deadbeef", true)]
+    #[case("fossil+https://example.com/repo#branch=trunk", false)]
+    #[case("fossil+https://example.com/repo#tag=v1.0.0", false)]
+    #[case("hg+https://example.com/repo#revision=deadbeef", true)]
+    #[case("hg+https://example.com/repo#branch=default", false)]
+    #[case("hg+https://example.com/repo#tag=v1.0.0", false)]
+    #[case("svn+https://example.com/repo#revision=42", true)]
+    #[case("svn+https://example.com/repo", false)]
+    fn source_is_reproducible(
+        #[case] input: &str,
+        #[case] expected: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(Source::from_str(input)?.is_reproducible(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(
+        "my-repo::git+https://example.com/repo.git?signed#tag=v1.2.3",
+        Some("my-repo"),
+        Some(VcsProtocol::Git),
+        Some(SourceFragment::Tag("v1.2.3".to_string()))
+    )]
+    #[case(
+        "my-repo::git+https://example.com/repo.git#commit=deadbeef",
+        Some("my-repo"),
+        Some(VcsProtocol::Git),
+        Some(SourceFragment::Commit("deadbeef".to_string()))
+    )]
+    #[case(
+        "svn+https://example.com/repo#revision=42",
+        None,
+        Some(VcsProtocol::Svn),
+        Some(SourceFragment::Revision("42".to_string()))
+    )]
+    #[case(
+        "repo::hg+https://example.com/repo#branch=default",
+        Some("repo"),
+        Some(VcsProtocol::Hg),
+        Some(SourceFragment::Branch("default".to_string()))
+    )]
+    #[case("bzr+https://example.com/repo", None, Some(VcsProtocol::Bzr), None)]
+    #[case(
+        "foo.tar.gz::https://example.com/download",
+        Some("foo.tar.gz"),
+        None,
+        None
+    )]
+    #[case("foo.patch", None, None, None)]
+    fn source_vcs_components(
+        #[case] input: &str,
+        #[case] filename: Option<&str>,
+        #[case] protocol: Option<VcsProtocol>,
+        #[case] fragment: Option<SourceFragment>,
+    ) -> testresult::TestResult<()> {
+        let source = Source::from_str(input)?;
+        assert_eq!(source.filename(), filename.map(PathBuf::from).as_ref());
+        assert_eq!(source.vcs_protocol(), protocol);
+        assert_eq!(source.fragment(), fragment);
+        assert_eq!(source.to_string(), input);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("https://example.com/foo-1.2.3.tar", true)]
+    #[case("https://example.com/foo-1.2.3.tar.gz", true)]
+    #[case("https://example.com/foo-1.2.3.tar.xz", true)]
+    #[case("https://example.com/foo-1.2.3.tar.zst", true)]
+    #[case("https://example.com/foo-1.2.3.tar.bz2", true)]
+    #[case("https://example.com/foo-1.2.3.tgz", true)]
+    #[case("https://example.com/foo-1.2.3.zip", true)]
+    #[case("foo.tar.gz::https://example.com/download", true)]
+    #[case("foo-1.2.3.tar.gz", true)]
+    #[case("bikeshed_colour.patch", false)]
+    #[case("https://example.com/foo-1.2.3.tar.gz.sig", false)]
+    #[case("foo.patch::https://example.com/foo.tar.gz", false)]
+    #[case("git+https://example.com/foo.tar.gz", false)]
+    fn source_is_archive(
+        #[case] input: &str,
+        #[case] expected: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(Source::from_str(input)?.is_archive(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(&["https://example.com/foo.tar.gz", "foo.patch"], Ok(()))]
+    #[case(
+        &["https://example.com/foo.tar.gz", "foo-2.tar.gz::https://example.org/foo.tar.gz"],
+        Ok(())
+    )]
+    #[case(
+        &["https://example.com/v1/foo.tar.gz", "https://example.org/v2/foo.tar.gz"],
+        Err(Error::DuplicateSourceFilename { name: PathBuf::from("foo.tar.gz") })
+    )]
+    #[case(
+        &["https://example.com/foo.tar.gz", "foo.tar.gz::https://example.org/download"],
+        Err(Error::DuplicateSourceFilename { name: PathBuf::from("foo.tar.gz") })
+    )]
+    #[case(
+        &["git+https://example.com/repo.git", "repo::https://example.org/repo.tar.gz"],
+        Err(Error::DuplicateSourceFilename { name: PathBuf::from("repo") })
+    )]
+    fn unique_source_filenames(
+        #[case] input: &[&str],
+        #[case] expected: Result<(), Error>,
+    ) -> testresult::TestResult<()> {
+        let sources = input
+            .iter()
+            .map(|source| Source::from_str(source))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(validate_unique_source_filenames(&sources), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::md5_only(
+        &["https://example.com/foo.tar.gz"],
+        &[ChecksumAlgorithm::Md5],
+        vec![ChecksumAlgorithm::Sha256],
+    )]
+    #[case::present(
+        &["https://example.com/foo.tar.gz", "foo.patch"],
+        &[ChecksumAlgorithm::Sha256],
+        vec![],
+    )]
+    #[case::mixed_vcs(
+        &["git+https://example.com/repo.git", "foo.patch"],
+        &[],
+        vec![ChecksumAlgorithm::Sha256],
+    )]
+    #[case::vcs_only(&["git+https://example.com/repo.git"], &[], vec![])]
+    #[case::no_sources(&[], &[], vec![])]
+    fn missing_checksums(
+        #[case] input: &[&str],
+        #[case] present: &[ChecksumAlgorithm],
+        #[case] expected: Vec<ChecksumAlgorithm>,
+    ) -> testresult::TestResult<()> {
+        let sources = input
+            .iter()
+            .map(|source| Source::from_str(source))
+            .collect::<Result<Vec<_>, _>>()?;
+        let policy = ChecksumPolicy::new(vec![ChecksumAlgorithm::Sha256]);
+        assert_eq!(
+            missing_checksum_arrays(&sources, present, &policy),
+            expected
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::same_url(
+        "https://example.com/foo.tar.gz",
+        "https://example.com/foo.tar.gz",
+        true
+    )]
+    #[case::renamed(
+        "https://example.com/foo.tar.gz",
+        "bar.tar.gz::https://example.com/foo.tar.gz",
+        true
+    )]
+    #[case::host_case(
+        "https://example.com/foo.tar.gz",
+        "https://EXAMPLE.com/foo.tar.gz",
+        true
+    )]
+    #[case::different_url(
+        "https://example.com/foo-1.0.tar.gz",
+        "https://example.com/foo-1.1.tar.gz",
+        false
+    )]
+    #[case::repinned(
+        "git+https://example.com/foo.git#commit=deadbeef",
+        "git+https://example.com/foo.git#commit=c0ffee",
+        false
+    )]
+    #[case::file_and_url("foo.tar.gz", "https://example.com/foo.tar.gz", false)]
+    fn source_content_id(
+        #[case] source_a: &str,
+        #[case] source_b: &str,
+        #[case] equal: bool,
+    ) -> testresult::TestResult<()> {
+        let content_id_a = Source::from_str(source_a)?.content_id();
+        let content_id_b = Source::from_str(source_b)?.content_id();
+        assert_eq!(content_id_a.len(), 16);
+        assert_eq!(content_id_a == content_id_b, equal);
+        Ok(())
+    }
+
+    #[test]
+    fn source_array_with_invalid_entries() {
+        let sources = parse_source_array(&[
+            "foo.patch",
+            "foo.patch::",
+            "https://example.com/foo.tar.gz",
+            "bar/foo.patch",
+        ]);
+        let invalid = sources
+            .iter()
+            .enumerate()
+            .filter_map(|(index, source)| source.is_err().then_some(index))
+            .collect::<Vec<_>>();
+
+        assert_eq!(sources.len(), 4);
+        assert_eq!(invalid, vec![1, 3]);
+    }
 }
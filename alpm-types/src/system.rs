@@ -1,5 +1,9 @@
-use serde::Serialize;
-use strum::{Display, EnumString};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString, ParseError};
+
+use crate::Error;
 
 /// CPU architecture
 ///
@@ -32,7 +36,18 @@ use strum::{Display, EnumString};
 /// assert_eq!("x86_64_v4", format!("{}", Architecture::X86_64V4));
 /// ```
 #[derive(
-    Clone, Copy, Debug, Display, EnumString, Eq, Ord, Hash, PartialEq, PartialOrd, Serialize,
+    Clone,
+    Copy,
+    Debug,
+    Deserialize,
+    Display,
+    EnumString,
+    Eq,
+    Ord,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Serialize,
 )]
 #[non_exhaustive]
 #[strum(serialize_all = "lowercase")]
@@ -72,6 +87,216 @@ pub enum Architecture {
     X86_64V4,
 }
 
+impl Architecture {
+    /// Creates an Architecture from a string, while also accepting common aliases used by other
+    /// distributions.
+    ///
+    /// In addition to all names accepted by [`Architecture::from_str`], the following aliases are
+    /// recognized:
+    ///
+    /// - `amd64` is mapped to [`Architecture::X86_64`]
+    /// - `arm64` is mapped to [`Architecture::Aarch64`]
+    /// - `i386` is mapped to [`Architecture::I686`], as distributions using this name usually
+    ///   target i686 compatible CPUs
+    ///
+    /// Use [`Architecture::from_str`] to only accept the names used by pacman.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the input is neither a valid architecture name nor a known alias.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Architecture;
+    ///
+    /// assert_eq!(
+    ///     Architecture::from_str_with_aliases("amd64"),
+    ///     Ok(Architecture::X86_64)
+    /// );
+    /// assert_eq!(
+    ///     Architecture::from_str_with_aliases("x86_64"),
+    ///     Ok(Architecture::X86_64)
+    /// );
+    /// assert!(Architecture::from_str("amd64").is_err());
+    /// ```
+    pub fn from_str_with_aliases(s: &str) -> Result<Self, ParseError> {
+        match s {
+            "amd64" => Ok(Architecture::X86_64),
+            "arm64" => Ok(Architecture::Aarch64),
+            "i386" => Ok(Architecture::I686),
+            _ => Architecture::from_str(s),
+        }
+    }
+
+    /// Returns the Architecture of the host system, if it is supported.
+    ///
+    /// The architecture is derived from [`std::env::consts::ARCH`], which describes the
+    /// architecture the code has been compiled for.
+    /// Returns [`None`] if the host architecture has no matching Architecture.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use alpm_types::Architecture;
+    ///
+    /// if cfg!(target_arch = "x86_64") {
+    ///     assert_eq!(Architecture::host(), Some(Architecture::X86_64));
+    /// }
+    /// ```
+    pub fn host() -> Option<Self> {
+        match std::env::consts::ARCH {
+            "aarch64" => Some(Architecture::Aarch64),
+            "arm" => Some(Architecture::Arm),
+            "riscv32" => Some(Architecture::Riscv32),
+            "riscv64" => Some(Architecture::Riscv64),
+            "x86" => Some(Architecture::I686),
+            "x86_64" => Some(Architecture::X86_64),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the Architecture is compatible with another one.
+    ///
+    /// [`Architecture::Any`] is compatible with all architectures. All other architectures are only
+    /// compatible with themselves (and [`Architecture::Any`]).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use alpm_types::Architecture;
+    ///
+    /// assert!(Architecture::Any.is_compatible_with(&Architecture::X86_64));
+    /// assert!(Architecture::X86_64.is_compatible_with(&Architecture::Any));
+    /// assert!(Architecture::X86_64.is_compatible_with(&Architecture::X86_64));
+    /// assert!(!Architecture::X86_64.is_compatible_with(&Architecture::Aarch64));
+    /// ```
+    pub fn is_compatible_with(&self, other: &Architecture) -> bool {
+        self == other || *self == Architecture::Any || *other == Architecture::Any
+    }
+
+    /// Returns the ELF machine constant (the `e_machine` field of the [_ELF Header_]) matching
+    /// the architecture.
+    ///
+    /// Returns [`None`] for [`Architecture::Any`], as it does not describe a specific machine.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use alpm_types::Architecture;
+    ///
+    /// assert_eq!(Architecture::X86_64.elf_machine(), Some(62));
+    /// assert_eq!(Architecture::Aarch64.elf_machine(), Some(183));
+    /// assert_eq!(Architecture::Any.elf_machine(), None);
+    /// ```
+    ///
+    /// [_ELF Header_]: https://en.wikipedia.org/wiki/Executable_and_Linkable_Format#ELF_header
+    pub fn elf_machine(&self) -> Option<u16> {
+        match self {
+            // EM_AARCH64
+            Architecture::Aarch64 => Some(183),
+            Architecture::Any => None,
+            // EM_ARM
+            Architecture::Arm | Architecture::Armv6h | Architecture::Armv7h => Some(40),
+            // EM_386
+            Architecture::I386
+            | Architecture::I486
+            | Architecture::I686
+            | Architecture::Pentium4 => Some(3),
+            // EM_RISCV
+            Architecture::Riscv32 | Architecture::Riscv64 => Some(243),
+            // EM_X86_64
+            Architecture::X86_64
+            | Architecture::X86_64V2
+            | Architecture::X86_64V3
+            | Architecture::X86_64V4 => Some(62),
+        }
+    }
+}
+
+/// Validates that all architectures of a package in a split package are also architectures of the
+/// package base.
+///
+/// ## Errors
+///
+/// Returns an [`Error::ArchitectureNotInPackageBase`] for the first architecture in `member` that
+/// is not part of `base`.
+///
+/// ## Examples
+///
+/// ```
+/// use alpm_types::{Architecture, validate_subpackage_arches};
+///
+/// let base = [Architecture::X86_64, Architecture::Aarch64];
+/// assert!(validate_subpackage_arches(&base, &[Architecture::X86_64]).is_ok());
+/// assert!(validate_subpackage_arches(&base, &[Architecture::Riscv64]).is_err());
+/// ```
+pub fn validate_subpackage_arches(
+    base: &[Architecture],
+    member: &[Architecture],
+) -> Result<(), Error> {
+    if let Some(architecture) = member
+        .iter()
+        .find(|architecture| !base.contains(architecture))
+    {
+        return Err(Error::ArchitectureNotInPackageBase {
+            architecture: *architecture,
+        });
+    }
+
+    Ok(())
+}
+
+/// Validates that all architecture specific fields use a declared architecture.
+///
+/// Architecture specific fields (e.g. `depends_aarch64`) are provided as tuples of the field name
+/// and the [`Architecture`] the field is specific to.
+///
+/// ## Errors
+///
+/// Returns an [`Error::UndeclaredArchitecture`] for the first field in `arch_suffixed_fields` that
+/// uses an architecture that is not part of `declared`.
+///
+/// ## Examples
+///
+/// ```
+/// use alpm_types::{Architecture, validate_arch_consistency};
+///
+/// let declared = [Architecture::X86_64];
+/// assert!(
+///     validate_arch_consistency(
+///         &declared,
+///         &[("depends_x86_64".to_string(), Architecture::X86_64)]
+///     )
+///     .is_ok()
+/// );
+/// assert!(
+///     validate_arch_consistency(
+///         &declared,
+///         &[("depends_aarch64".to_string(), Architecture::Aarch64)]
+///     )
+///     .is_err()
+/// );
+/// ```
+pub fn validate_arch_consistency(
+    declared: &[Architecture],
+    arch_suffixed_fields: &[(String, Architecture)],
+) -> Result<(), Error> {
+    if let Some((field, architecture)) = arch_suffixed_fields
+        .iter()
+        .find(|(_, architecture)| !declared.contains(architecture))
+    {
+        return Err(Error::UndeclaredArchitecture {
+            field: field.clone(),
+            architecture: *architecture,
+        });
+    }
+
+    Ok(())
+}
+
 /// ELF architecture format.
 ///
 /// This enum represents the _Class_ field in the [_ELF Header_].
@@ -116,10 +341,7 @@ pub enum ElfArchitectureFormat {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
-
     use rstest::rstest;
-    use strum::ParseError;
 
     use super::*;
 
@@ -164,6 +386,74 @@ mod tests {
         assert_eq!(arch_str, format!("{}", arch));
     }
 
+    #[rstest]
+    #[case("amd64", Ok(Architecture::X86_64))]
+    #[case("arm64", Ok(Architecture::Aarch64))]
+    #[case("i386", Ok(Architecture::I686))]
+    #[case("x86_64", Ok(Architecture::X86_64))]
+    #[case("aarch64", Ok(Architecture::Aarch64))]
+    #[case("any", Ok(Architecture::Any))]
+    #[case("foo", Err(ParseError::VariantNotFound))]
+    fn architecture_from_string_with_aliases(
+        #[case] s: &str,
+        #[case] arch: Result<Architecture, ParseError>,
+    ) {
+        assert_eq!(Architecture::from_str_with_aliases(s), arch);
+    }
+
+    #[rstest]
+    #[case("amd64")]
+    #[case("arm64")]
+    fn architecture_aliases_not_strict(#[case] s: &str) {
+        assert_eq!(Architecture::from_str(s), Err(ParseError::VariantNotFound));
+    }
+
+    #[rstest]
+    #[case(&[Architecture::X86_64, Architecture::Aarch64], &[], Ok(()))]
+    #[case(&[Architecture::X86_64, Architecture::Aarch64], &[Architecture::Aarch64], Ok(()))]
+    #[case(
+        &[Architecture::X86_64, Architecture::Aarch64],
+        &[Architecture::X86_64, Architecture::Aarch64],
+        Ok(())
+    )]
+    #[case(
+        &[Architecture::X86_64, Architecture::Aarch64],
+        &[Architecture::X86_64, Architecture::Riscv64],
+        Err(Error::ArchitectureNotInPackageBase { architecture: Architecture::Riscv64 })
+    )]
+    #[case(
+        &[Architecture::X86_64],
+        &[Architecture::Any],
+        Err(Error::ArchitectureNotInPackageBase { architecture: Architecture::Any })
+    )]
+    fn subpackage_arches(
+        #[case] base: &[Architecture],
+        #[case] member: &[Architecture],
+        #[case] result: Result<(), Error>,
+    ) {
+        assert_eq!(validate_subpackage_arches(base, member), result);
+    }
+
+    #[rstest]
+    #[case(Architecture::Aarch64, Some(183))]
+    #[case(Architecture::Any, None)]
+    #[case(Architecture::Arm, Some(40))]
+    #[case(Architecture::Armv6h, Some(40))]
+    #[case(Architecture::Armv7h, Some(40))]
+    #[case(Architecture::I386, Some(3))]
+    #[case(Architecture::I486, Some(3))]
+    #[case(Architecture::I686, Some(3))]
+    #[case(Architecture::Pentium4, Some(3))]
+    #[case(Architecture::Riscv32, Some(243))]
+    #[case(Architecture::Riscv64, Some(243))]
+    #[case(Architecture::X86_64, Some(62))]
+    #[case(Architecture::X86_64V2, Some(62))]
+    #[case(Architecture::X86_64V3, Some(62))]
+    #[case(Architecture::X86_64V4, Some(62))]
+    fn architecture_elf_machine(#[case] arch: Architecture, #[case] elf_machine: Option<u16>) {
+        assert_eq!(arch.elf_machine(), elf_machine);
+    }
+
     #[rstest]
     #[case("32", Ok(ElfArchitectureFormat::Bit32))]
     #[case("64", Ok(ElfArchitectureFormat::Bit64))]
@@ -184,4 +474,63 @@ mod tests {
     ) {
         assert_eq!(arch_str, format!("{}", arch));
     }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn architecture_host_x86_64() {
+        assert_eq!(Architecture::host(), Some(Architecture::X86_64));
+    }
+
+    #[rstest]
+    #[case(Architecture::Any, Architecture::Any, true)]
+    #[case(Architecture::Any, Architecture::X86_64, true)]
+    #[case(Architecture::Aarch64, Architecture::Any, true)]
+    #[case(Architecture::X86_64, Architecture::X86_64, true)]
+    #[case(Architecture::X86_64, Architecture::X86_64V3, false)]
+    #[case(Architecture::X86_64, Architecture::Aarch64, false)]
+    fn architecture_is_compatible_with(
+        #[case] arch: Architecture,
+        #[case] other: Architecture,
+        #[case] compatible: bool,
+    ) {
+        assert_eq!(arch.is_compatible_with(&other), compatible);
+        assert_eq!(other.is_compatible_with(&arch), compatible);
+    }
+
+    #[rstest]
+    #[case(&[Architecture::X86_64], &[], Ok(()))]
+    #[case(
+        &[Architecture::X86_64, Architecture::Aarch64],
+        &[("depends_x86_64", Architecture::X86_64), ("provides_aarch64", Architecture::Aarch64)],
+        Ok(()),
+    )]
+    #[case(
+        &[Architecture::X86_64],
+        &[("depends_x86_64", Architecture::X86_64), ("depends_aarch64", Architecture::Aarch64)],
+        Err(Error::UndeclaredArchitecture {
+            field: "depends_aarch64".to_string(),
+            architecture: Architecture::Aarch64,
+        }),
+    )]
+    fn arch_consistency(
+        #[case] declared: &[Architecture],
+        #[case] fields: &[(&str, Architecture)],
+        #[case] expected: Result<(), Error>,
+    ) {
+        let fields = fields
+            .iter()
+            .map(|(field, architecture)| (field.to_string(), *architecture))
+            .collect::<Vec<_>>();
+        assert_eq!(validate_arch_consistency(declared, &fields), expected);
+    }
+
+    #[rstest]
+    #[case(Architecture::Any)]
+    #[case(Architecture::X86_64)]
+    #[case(Architecture::X86_64V3)]
+    fn architecture_serde_roundtrip(#[case] arch: Architecture) -> testresult::TestResult<()> {
+        let json = serde_json::to_string(&arch)?;
+        assert_eq!(serde_json::from_str::<Architecture>(&json)?, arch);
+        Ok(())
+    }
 }
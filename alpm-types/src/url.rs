@@ -249,6 +249,47 @@ impl Display for SourceUrl {
 }
 
 impl SourceUrl {
+    /// Returns a copy of the [`SourceUrl`] with the host of its URL replaced by `new_host`.
+    ///
+    /// All other components of the URL (e.g. scheme, path, query and fragment), as well as the VCS
+    /// information, are preserved.
+    /// This allows e.g. to redirect the retrieval of sources to a mirror.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `new_host` is empty or not a valid host.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::SourceUrl;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let url = SourceUrl::from_str("git+https://a.org/project.git#tag=v1.0.0")?;
+    /// assert_eq!(
+    ///     url.with_host("b.org")?.to_string(),
+    ///     "git+https://b.org/project.git#tag=v1.0.0"
+    /// );
+    /// assert!(url.with_host("").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_host(&self, new_host: &str) -> Result<SourceUrl, Error> {
+        if new_host.is_empty() {
+            return Err(url::ParseError::EmptyHost.into());
+        }
+
+        let mut url = self.url.inner().clone();
+        url.set_host(Some(new_host))?;
+
+        Ok(SourceUrl {
+            url: Url::new(url)?,
+            vcs_info: self.vcs_info.clone(),
+        })
+    }
+
     /// Parses a full [`SourceUrl`] from a string slice.
     fn parser(input: &mut &str) -> ModalResult<SourceUrl> {
         // Check if we should use a VCS for this URL.
@@ -378,21 +419,103 @@ impl VcsInfo {
             }
         }
     }
+
+    /// Returns the [`VcsProtocol`] of the VCS.
+    pub fn protocol(&self) -> VcsProtocol {
+        match self {
+            VcsInfo::Bzr { .. } => VcsProtocol::Bzr,
+            VcsInfo::Fossil { .. } => VcsProtocol::Fossil,
+            VcsInfo::Git { .. } => VcsProtocol::Git,
+            VcsInfo::Hg { .. } => VcsProtocol::Hg,
+            VcsInfo::Svn { .. } => VcsProtocol::Svn,
+        }
+    }
+
+    /// Returns the VCS-specific fragment as a [`SourceFragment`], if one is set.
+    pub fn fragment(&self) -> Option<SourceFragment> {
+        match self {
+            VcsInfo::Bzr { fragment } => fragment.as_ref().map(|fragment| match fragment {
+                BzrFragment::Revision(revision) => SourceFragment::Revision(revision.clone()),
+            }),
+            VcsInfo::Fossil { fragment } => fragment.as_ref().map(|fragment| match fragment {
+                FossilFragment::Branch(branch) => SourceFragment::Branch(branch.clone()),
+                FossilFragment::Commit(commit) => SourceFragment::Commit(commit.clone()),
+                FossilFragment::Tag(tag) => SourceFragment::Tag(tag.clone()),
+            }),
+            VcsInfo::Git { fragment, .. } => fragment.as_ref().map(|fragment| match fragment {
+                GitFragment::Branch(branch) => SourceFragment::Branch(branch.clone()),
+                GitFragment::Commit(commit) => SourceFragment::Commit(commit.clone()),
+                GitFragment::Tag(tag) => SourceFragment::Tag(tag.clone()),
+            }),
+            VcsInfo::Hg { fragment } => fragment.as_ref().map(|fragment| match fragment {
+                HgFragment::Branch(branch) => SourceFragment::Branch(branch.clone()),
+                HgFragment::Revision(revision) => SourceFragment::Revision(revision.clone()),
+                HgFragment::Tag(tag) => SourceFragment::Tag(tag.clone()),
+            }),
+            VcsInfo::Svn { fragment } => fragment.as_ref().map(|fragment| match fragment {
+                SvnFragment::Revision(revision) => SourceFragment::Revision(revision.clone()),
+            }),
+        }
+    }
+}
+
+/// The object a VCS source refers to.
+///
+/// This is a VCS-independent representation of the fragment of a VCS source URL (e.g.
+/// `#tag=v1.0.0`). Which of the variants are supported depends on the [`VcsProtocol`].
+///
+/// ## Examples
+///
+/// ```
+/// use alpm_types::SourceFragment;
+///
+/// assert_eq!(
+///     SourceFragment::Tag("v1.0.0".to_string()).to_string(),
+///     "tag=v1.0.0"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceFragment {
+    /// A branch
+    Branch(String),
+    /// A commit
+    Commit(String),
+    /// A revision
+    Revision(String),
+    /// A tag
+    Tag(String),
+}
+
+impl Display for SourceFragment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceFragment::Branch(branch) => write!(f, "branch={branch}"),
+            SourceFragment::Commit(commit) => write!(f, "commit={commit}"),
+            SourceFragment::Revision(revision) => write!(f, "revision={revision}"),
+            SourceFragment::Tag(tag) => write!(f, "tag={tag}"),
+        }
+    }
 }
 
 /// A VCS protocol
 ///
-/// This identifier is only used during parsing to have some static representation of the detected
+/// During parsing, this identifier is used to have some static representation of the detected
 /// VCS.
 /// This is necessary as the fragment and the query are parsed at a later step and we have to
 /// keep track of the VCS somehow.
-#[derive(strum::EnumString, strum::Display)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, strum::EnumString, strum::Display)]
 #[strum(serialize_all = "lowercase")]
-enum VcsProtocol {
+pub enum VcsProtocol {
+    /// Bazaar
     Bzr,
+    /// Fossil
     Fossil,
+    /// Git
     Git,
+    /// Mercurial
     Hg,
+    /// Subversion
     Svn,
 }
 
@@ -849,4 +972,43 @@ mod tests {
             "Error:\n=====\n{pretty_error}\n=====\nshould contain snippet:\n\n{error_snippet}"
         );
     }
+
+    #[rstest]
+    #[case("https://a.org/p", "b.org", "https://b.org/p")]
+    #[case(
+        "https://a.org:8080/p/file.tar.gz?query=1#fragment",
+        "mirror.b.org",
+        "https://mirror.b.org:8080/p/file.tar.gz?query=1#fragment"
+    )]
+    #[case(
+        "git+https://a.org/project.git?signed#tag=v1.0.0",
+        "b.org",
+        "git+https://b.org/project.git?signed#tag=v1.0.0"
+    )]
+    fn source_url_with_host(
+        #[case] input: &str,
+        #[case] host: &str,
+        #[case] expected: &str,
+    ) -> TestResult {
+        let source_url = SourceUrl::from_str(input)?;
+        assert_eq!(source_url.with_host(host)?.to_string(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("https://a.org/p", "", Error::InvalidUrl(url::ParseError::EmptyHost))]
+    #[case(
+        "https://a.org/p",
+        "in valid",
+        Error::InvalidUrl(url::ParseError::IdnaError)
+    )]
+    fn source_url_with_invalid_host(
+        #[case] input: &str,
+        #[case] host: &str,
+        #[case] error: Error,
+    ) -> TestResult {
+        let source_url = SourceUrl::from_str(input)?;
+        assert_eq!(source_url.with_host(host), Err(error));
+        Ok(())
+    }
 }
@@ -1,17 +1,18 @@
 use std::{
     cmp::Ordering,
     fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
     iter::Peekable,
-    num::NonZeroUsize,
+    num::{IntErrorKind, NonZeroU64},
     str::{CharIndices, Chars, FromStr},
 };
 
 use lazy_regex::{Lazy, lazy_regex};
 use regex::Regex;
 use semver::Version as SemverVersion;
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::{Architecture, error::Error};
+use crate::{Architecture, error::Error, parse::deserialize_from_str};
 
 pub(crate) static PKGREL_REGEX: Lazy<Regex> = lazy_regex!(r"^[0-9]+(\.[0-9]+)?$");
 pub(crate) static PKGVER_REGEX: Lazy<Regex> = lazy_regex!(r"^([[:alnum:]][[:alnum:]_+.]*)$");
@@ -96,7 +97,11 @@ impl Display for BuildToolVersion {
 /// a `":"` (e.g. `1:` is added to `0.10.0-1` to form `1:0.10.0-1` which then orders newer than
 /// `1.0.0-1`).
 ///
-/// An Epoch wraps a usize that is guaranteed to be greater than `0`.
+/// An Epoch wraps a u64 that is guaranteed to be greater than `0`.
+/// Values larger than [`u64::MAX`] are rejected with an [`Error::InvalidInteger`] of kind
+/// [`IntErrorKind::PosOverflow`](std::num::IntErrorKind::PosOverflow).
+/// An Epoch is always displayed in its canonical decimal representation (e.g. `01` is displayed as
+/// `1`).
 ///
 /// ## Examples
 /// ```
@@ -106,15 +111,42 @@ impl Display for BuildToolVersion {
 ///
 /// assert!(Epoch::from_str("1").is_ok());
 /// assert!(Epoch::from_str("0").is_err());
+/// assert!(Epoch::from_str("99999999999999999999").is_err());
 /// ```
-#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
-pub struct Epoch(pub NonZeroUsize);
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Epoch(pub NonZeroU64);
 
 impl Epoch {
     /// Create a new Epoch
-    pub fn new(epoch: NonZeroUsize) -> Self {
+    pub fn new(epoch: NonZeroU64) -> Self {
         Epoch(epoch)
     }
+
+    /// Returns the Epoch incremented by one
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error::InvalidInteger`] if the Epoch would overflow.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Epoch;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(Epoch::from_str("1")?.increment()?, Epoch::from_str("2")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn increment(&self) -> Result<Epoch, Error> {
+        self.0
+            .checked_add(1)
+            .map(Epoch)
+            .ok_or(Error::InvalidInteger {
+                kind: IntErrorKind::PosOverflow,
+            })
+    }
 }
 
 impl FromStr for Epoch {
@@ -124,7 +156,7 @@ impl FromStr for Epoch {
         match s.parse() {
             Ok(epoch) => Ok(Epoch(epoch)),
             Err(source) => Err(Error::InvalidInteger {
-                kind: source.kind().clone(),
+                kind: *source.kind(),
             }),
         }
     }
@@ -157,7 +189,7 @@ impl Display for Epoch {
 /// assert!(PackageRelease::new("a".to_string()).is_err());
 /// assert!(PackageRelease::new("1.a".to_string()).is_err());
 /// ```
-#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct PackageRelease(String);
 
 impl PackageRelease {
@@ -170,6 +202,45 @@ impl PackageRelease {
     pub fn inner(&self) -> &str {
         &self.0
     }
+
+    /// Returns the PackageRelease incremented by one
+    ///
+    /// A PackageRelease with a sub-release (e.g. `1.1`) has its sub-release incremented, otherwise
+    /// the PackageRelease itself is incremented.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error::InvalidInteger`] if the incremented component would overflow.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::PackageRelease;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(PackageRelease::from_str("1")?.increment()?.to_string(), "2");
+    /// assert_eq!(
+    ///     PackageRelease::from_str("1.1")?.increment()?.to_string(),
+    ///     "1.2"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn increment(&self) -> Result<PackageRelease, Error> {
+        let (prefix, component) = match self.0.rsplit_once('.') {
+            Some((major, sub)) => (format!("{major}."), sub),
+            None => (String::new(), self.0.as_str()),
+        };
+        let component = component
+            .parse::<u64>()?
+            .checked_add(1)
+            .ok_or(Error::InvalidInteger {
+                kind: IntErrorKind::PosOverflow,
+            })?;
+
+        PackageRelease::new(format!("{prefix}{component}"))
+    }
 }
 
 impl FromStr for PackageRelease {
@@ -188,12 +259,51 @@ impl FromStr for PackageRelease {
     }
 }
 
+impl Ord for PackageRelease {
+    /// Compares two PackageReleases segment-wise, just like [`PackageVersion`]s (e.g. `2` < `10`)
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_segments(self.inner(), other.inner())
+    }
+}
+
+impl PartialOrd for PackageRelease {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for PackageRelease {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for PackageRelease {}
+
+impl Hash for PackageRelease {
+    /// Hashes a normalized form of the release, so that the [`Hash`]/[`Eq`] contract holds (e.g.
+    /// `1` and `01` are equal).
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_segments(self.inner(), state);
+    }
+}
+
 impl Display for PackageRelease {
     fn fmt(&self, fmt: &mut Formatter) -> std::fmt::Result {
         write!(fmt, "{}", self.inner())
     }
 }
 
+impl<'de> Deserialize<'de> for PackageRelease {
+    /// Deserializes a [`PackageRelease`] from its string representation.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_from_str(deserializer)
+    }
+}
+
 /// A pkgver of a package
 ///
 /// PackageVersion is used to denote the upstream version of a package.
@@ -234,10 +344,148 @@ impl PackageVersion {
         &self.0
     }
 
+    /// Return the first segment in which this version differs from another version.
+    ///
+    /// Returns `None` if all segments are equal.
+    fn first_segment_difference(&self, other: &PackageVersion) -> Option<VersionDifference> {
+        let mut self_segments = self.segments();
+        let mut other_segments = other.segments();
+        let mut index = 0;
+
+        loop {
+            match (self_segments.next(), other_segments.next()) {
+                (None, None) => return None,
+                (left, right) if left != right => {
+                    return Some(VersionDifference::PkgverSegment {
+                        index,
+                        left: left.map(|segment| segment.segment.to_string()),
+                        right: right.map(|segment| segment.segment.to_string()),
+                    });
+                }
+                _ => index += 1,
+            }
+        }
+    }
+
     /// Return an iterator over all segments of this version.
     pub fn segments(&self) -> VersionSegments {
         VersionSegments::new(&self.0)
     }
+
+    /// Returns the canonical form of the PackageVersion
+    ///
+    /// All PackageVersions, that are equal according to [`vercmp`], have the same canonical form.
+    /// In it, numeric segments have no leading zeros, each group of delimiters is written as the
+    /// same amount of `.` and trailing delimiters are reduced to a single `.`.
+    ///
+    /// Note, that the number of segments is significant (e.g. `1.0` and `1.0.0` are not equal).
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::PackageVersion;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     PackageVersion::from_str("01.002_3+rc1")?
+    ///         .normalized()
+    ///         .to_string(),
+    ///     "1.2.3.rc1"
+    /// );
+    /// assert_eq!(
+    ///     PackageVersion::from_str("1.0")?.normalized().to_string(),
+    ///     "1.0"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn normalized(&self) -> PackageVersion {
+        let mut normalized = String::with_capacity(self.0.len());
+        for segment in self.segments() {
+            if segment.is_empty() {
+                normalized.push('.');
+                continue;
+            }
+
+            normalized.push_str(&".".repeat(segment.delimiters));
+            if segment.chars().all(|char| char.is_ascii_digit()) {
+                match segment.segment.trim_start_matches('0') {
+                    "" => normalized.push('0'),
+                    trimmed => normalized.push_str(trimmed),
+                }
+            } else {
+                normalized.push_str(segment.segment);
+            }
+        }
+
+        PackageVersion(normalized)
+    }
+
+    /// Returns a new PackageVersion, in which the dot-separated component matching `level` is
+    /// incremented.
+    ///
+    /// All components following the incremented one are set to `0`.
+    /// If the version has fewer components than required by `level`, the missing components are
+    /// added as `0` before incrementing.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error::InvalidInteger`] if the component to increment is not numeric.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{BumpLevel, PackageVersion};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let version = PackageVersion::from_str("1.2.3")?;
+    /// assert_eq!(version.bump(BumpLevel::Major)?.to_string(), "2.0.0");
+    /// assert_eq!(version.bump(BumpLevel::Minor)?.to_string(), "1.3.0");
+    /// assert_eq!(version.bump(BumpLevel::Patch)?.to_string(), "1.2.4");
+    ///
+    /// assert!(
+    ///     PackageVersion::from_str("1.2.3rc1")?
+    ///         .bump(BumpLevel::Patch)
+    ///         .is_err()
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bump(&self, level: BumpLevel) -> Result<PackageVersion, Error> {
+        let index = level as usize;
+        let mut components = self.0.split('.').map(str::to_string).collect::<Vec<_>>();
+        if components.len() <= index {
+            components.resize(index + 1, "0".to_string());
+        }
+
+        let component =
+            components[index]
+                .parse::<u64>()?
+                .checked_add(1)
+                .ok_or(Error::InvalidInteger {
+                    kind: IntErrorKind::PosOverflow,
+                })?;
+        components[index] = component.to_string();
+        for component in components.iter_mut().skip(index + 1) {
+            *component = "0".to_string();
+        }
+
+        PackageVersion::new(components.join("."))
+    }
+}
+
+/// The level of a dot-separated [`PackageVersion`] component that is incremented by
+/// [`PackageVersion::bump`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BumpLevel {
+    /// The first component (e.g. `1` in `1.2.3`)
+    Major = 0,
+    /// The second component (e.g. `2` in `1.2.3`)
+    Minor = 1,
+    /// The third component (e.g. `3` in `1.2.3`)
+    Patch = 2,
 }
 
 impl FromStr for PackageVersion {
@@ -262,6 +510,16 @@ impl Display for PackageVersion {
     }
 }
 
+impl<'de> Deserialize<'de> for PackageVersion {
+    /// Deserializes a [`PackageVersion`] from its string representation.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_from_str(deserializer)
+    }
+}
+
 /// This struct represents a single segment in a version string.
 /// `VersionSegment`s are returned by the [VersionSegments] iterator, which is responsible for
 /// splitting a version string into its segments.
@@ -449,244 +707,250 @@ impl<'a> Iterator for VersionSegments<'a> {
 }
 
 impl Ord for PackageVersion {
-    /// This block implements the logic to determine which of two package versions is newer or
-    /// whether they're considered equal.
-    ///
-    /// This logic is surprisingly complex as it mirrors the current C-alpmlib implementation for
-    /// backwards compatibility reasons.
-    /// <https://gitlab.archlinux.org/pacman/pacman/-/blob/a2d029388c7c206f5576456f91bfbea2dca98c96/lib/libalpm/version.c#L83-217>
     fn cmp(&self, other: &Self) -> Ordering {
-        // Equal strings are considered equal versions.
-        if self.inner() == other.inner() {
-            return Ordering::Equal;
-        }
-
-        let mut self_segments = self.segments().peekable();
-        let mut other_segments = other.segments().peekable();
+        cmp_segments(self.inner(), other.inner())
+    }
+}
 
-        // Loop through both versions' segments and compare them.
-        loop {
-            // Try to get the next segments
-            let self_segment = self_segments.next();
-            let other_segment = other_segments.next();
-
-            // Make sure that there's a next segment for both versions.
-            let (self_segment, other_segment) = match (self_segment, other_segment) {
-                // Both segments exist, we continue after match.
-                (Some(self_seg), Some(other_seg)) => (self_seg, other_seg),
-
-                // Both versions reached their end and are thereby equal.
-                (None, None) => return Ordering::Equal,
-
-                // One version is longer than the other.
-                // Sadly, this isn't trivial to handle.
-                //
-                // The rules are as follows:
-                // Versions with at least two additional segments are always newer.
-                // -> `1.a.0` > `1`
-                //        ⤷ Two more segment, include one delimiter
-                // -> `1.a0` > `1`
-                //        ⤷ Two more segment, thereby an alphanumerical string.
-                //
-                // If one version is exactly one segment and has a delimiter, it's also considered
-                // newer.
-                // -> `1.0` > `1`
-                // -> `1.a` > `1`
-                //      ⤷ Delimiter exists, thereby newer
-                //
-                // If one version is exactly one segment longer and that segment is
-                // purely alphabetic **without** a leading delimiter, that segment is considered
-                // older. The reason for this is to handle pre-releases (e.g. alpha/beta).
-                // -> `1.0alpha` > `1.0`
-                //          ⤷ Purely alphabetic last segment, without delimiter and thereby older.
-                (Some(seg), None) => {
-                    // There's at least one more segment, making `Self` effectively newer.
-                    // It's either an alphanumeric string or another segment separated with a
-                    // delimiter.
-                    if self_segments.next().is_some() {
-                        return Ordering::Greater;
-                    }
-
-                    // We now know that this is also the last segment of `self`.
-                    // If the current segment has a leading delimiter, it's also considered newer.
-                    if seg.delimiters > 0 {
-                        return Ordering::Greater;
-                    }
-
-                    // If all chars are alphabetic, `self` is consider older.
-                    if !seg.is_empty() && seg.chars().all(char::is_alphabetic) {
-                        return Ordering::Less;
-                    }
+/// Compares two version strings segment by segment
+///
+/// This function implements the logic to determine which of two package versions is newer or
+/// whether they're considered equal.
+///
+/// This logic is surprisingly complex as it mirrors the current C-alpmlib implementation for
+/// backwards compatibility reasons.
+/// <https://gitlab.archlinux.org/pacman/pacman/-/blob/a2d029388c7c206f5576456f91bfbea2dca98c96/lib/libalpm/version.c#L83-217>
+fn cmp_segments(self_version: &str, other_version: &str) -> Ordering {
+    // Equal strings are considered equal versions.
+    if self_version == other_version {
+        return Ordering::Equal;
+    }
+
+    let mut self_segments = VersionSegments::new(self_version).peekable();
+    let mut other_segments = VersionSegments::new(other_version).peekable();
+
+    // Loop through both versions' segments and compare them.
+    loop {
+        // Try to get the next segments
+        let self_segment = self_segments.next();
+        let other_segment = other_segments.next();
+
+        // Make sure that there's a next segment for both versions.
+        let (self_segment, other_segment) = match (self_segment, other_segment) {
+            // Both segments exist, we continue after match.
+            (Some(self_seg), Some(other_seg)) => (self_seg, other_seg),
+
+            // Both versions reached their end and are thereby equal.
+            (None, None) => return Ordering::Equal,
+
+            // One version is longer than the other.
+            // Sadly, this isn't trivial to handle.
+            //
+            // The rules are as follows:
+            // Versions with at least two additional segments are always newer.
+            // -> `1.a.0` > `1`
+            //        ⤷ Two more segment, include one delimiter
+            // -> `1.a0` > `1`
+            //        ⤷ Two more segment, thereby an alphanumerical string.
+            //
+            // If one version is exactly one segment and has a delimiter, it's also considered
+            // newer.
+            // -> `1.0` > `1`
+            // -> `1.a` > `1`
+            //      ⤷ Delimiter exists, thereby newer
+            //
+            // If one version is exactly one segment longer and that segment is
+            // purely alphabetic **without** a leading delimiter, that segment is considered
+            // older. The reason for this is to handle pre-releases (e.g. alpha/beta).
+            // -> `1.0alpha` > `1.0`
+            //          ⤷ Purely alphabetic last segment, without delimiter and thereby older.
+            (Some(seg), None) => {
+                // There's at least one more segment, making `Self` effectively newer.
+                // It's either an alphanumeric string or another segment separated with a
+                // delimiter.
+                if self_segments.next().is_some() {
+                    return Ordering::Greater;
+                }
 
+                // We now know that this is also the last segment of `self`.
+                // If the current segment has a leading delimiter, it's also considered newer.
+                if seg.delimiters > 0 {
                     return Ordering::Greater;
                 }
 
-                // This is the same logic as above, but inverted.
-                (None, Some(seg)) => {
-                    if other_segments.next().is_some() {
-                        return Ordering::Less;
-                    }
-                    if seg.delimiters > 0 {
-                        return Ordering::Less;
-                    }
-                    if !seg.is_empty() && seg.chars().all(char::is_alphabetic) {
-                        return Ordering::Greater;
-                    }
+                // If all chars are alphabetic, `self` is consider older.
+                if !seg.is_empty() && seg.chars().all(char::is_alphabetic) {
                     return Ordering::Less;
                 }
-            };
-
-            // Special case:
-            // One or both of the segments is empty. That means that the end of the version string
-            // has been reached, but there were some trailing delimiters.
-            // Possible examples of how this might look:
-            // `1.0.` < `1.0.0`
-            // `1.0.` == `1.0.`
-            // `1.0.alpha` < `1.0.`
-            if other_segment.is_empty() && self_segment.is_empty() {
-                // Both reached the end of their version with a trailing delimiter.
-                // Counterintuitively, the trailing delimiter count is not considered and both
-                // versions are considered equal
-                // `1.0....` == `1.0.`
-                return Ordering::Equal;
-            } else if self_segment.is_empty() {
-                // Check if there's at least one other segment on the `other` version.
-                // If so, that one is always considered newer.
-                // `1.0.1.1` > `1.0.`
-                // `1.0.alpha1` > `1.0.`
-                // `1.0.alpha.1` > `1.0.`
-                //           ⤷ More segments and thereby always newer
-                if other_segments.peek().is_some() {
+
+                return Ordering::Greater;
+            }
+
+            // This is the same logic as above, but inverted.
+            (None, Some(seg)) => {
+                if other_segments.next().is_some() {
                     return Ordering::Less;
                 }
-
-                // In case there's no further segment, both versions reached the last segment.
-                // We now have to consider the special case where `other` is purely alphabetic.
-                // If that's the case, `self` will be considered newer, as the alphabetic string
-                // indicates a pre-release,
-                // `1.0.` > `1.0.alpha`.
-                //                   ⤷ Purely alphabetic last segment and thereby older.
-                //
-                // Also, we know that `other_segment` isn't empty at this point.
-                if other_segment.chars().all(char::is_alphabetic) {
-                    return Ordering::Greater;
+                if seg.delimiters > 0 {
+                    return Ordering::Less;
                 }
-
-                // In all other cases, `other` is newer.
-                return Ordering::Less;
-            } else if other_segment.is_empty() {
-                // Check docs above, as it's the same logic as above, just inverted.
-                if self_segments.peek().is_some() {
+                if !seg.is_empty() && seg.chars().all(char::is_alphabetic) {
                     return Ordering::Greater;
                 }
+                return Ordering::Less;
+            }
+        };
 
-                if self_segment.chars().all(char::is_alphabetic) {
-                    return Ordering::Less;
-                }
+        // Special case:
+        // One or both of the segments is empty. That means that the end of the version string
+        // has been reached, but there were some trailing delimiters.
+        // Possible examples of how this might look:
+        // `1.0.` < `1.0.0`
+        // `1.0.` == `1.0.`
+        // `1.0.alpha` < `1.0.`
+        if other_segment.is_empty() && self_segment.is_empty() {
+            // Both reached the end of their version with a trailing delimiter.
+            // Counterintuitively, the trailing delimiter count is not considered and both
+            // versions are considered equal
+            // `1.0....` == `1.0.`
+            return Ordering::Equal;
+        } else if self_segment.is_empty() {
+            // Check if there's at least one other segment on the `other` version.
+            // If so, that one is always considered newer.
+            // `1.0.1.1` > `1.0.`
+            // `1.0.alpha1` > `1.0.`
+            // `1.0.alpha.1` > `1.0.`
+            //           ⤷ More segments and thereby always newer
+            if other_segments.peek().is_some() {
+                return Ordering::Less;
+            }
 
+            // In case there's no further segment, both versions reached the last segment.
+            // We now have to consider the special case where `other` is purely alphabetic.
+            // If that's the case, `self` will be considered newer, as the alphabetic string
+            // indicates a pre-release,
+            // `1.0.` > `1.0.alpha`.
+            //                   ⤷ Purely alphabetic last segment and thereby older.
+            //
+            // Also, we know that `other_segment` isn't empty at this point.
+            if other_segment.chars().all(char::is_alphabetic) {
                 return Ordering::Greater;
             }
 
-            // We finally reached the end handling special cases when the version string ended.
-            // From now on, we know that we have two actual segments that might be prefixed by
-            // some delimiters.
-
-            // Special case:
-            // If one of the segments has more leading delimiters as the other, it's considered
-            // newer.
-            // `1..0.0` > `1.2.0`
-            //         ⤷ Two delimiters, thereby always newer.
-            // `1..0.0` < `1..2.0`
-            //                ⤷ Same amount of delimiters, now `2 > 0`
-            if self_segment.delimiters != other_segment.delimiters {
-                return self_segment.delimiters.cmp(&other_segment.delimiters);
+            // In all other cases, `other` is newer.
+            return Ordering::Less;
+        } else if other_segment.is_empty() {
+            // Check docs above, as it's the same logic as above, just inverted.
+            if self_segments.peek().is_some() {
+                return Ordering::Greater;
             }
 
-            // Check whether any of the segments are numeric.
-            // Numeric segments are always considered newer than non-numeric segments.
-            // E.g. `1.0.0` > `1.lol.0`
-            //         ⤷ `0` vs `lol`. `0` is purely numeric and bigger than a alphanumeric one.
-            let self_is_numeric =
-                !self_segment.is_empty() && self_segment.chars().all(char::is_numeric);
-            let other_is_numeric =
-                !other_segment.is_empty() && other_segment.chars().all(char::is_numeric);
-
-            if self_is_numeric && !other_is_numeric {
-                return Ordering::Greater;
-            } else if !self_is_numeric && other_is_numeric {
+            if self_segment.chars().all(char::is_alphabetic) {
                 return Ordering::Less;
             }
 
-            // In case both are numeric, we do a number comparison.
-            // We can parse the string as we know that they only consist of digits, hence the
-            // unwrap.
-            //
-            // Trailing zeroes are to be ignored, which is automatically done by Rust's number
-            // parser. E.g. `1.0001.1` == `1.1.1`
-            //                  ⤷ `000` is ignored in comparison.
-            if self_is_numeric && other_is_numeric {
-                let ordering = self_segment
-                    .parse::<usize>()
-                    .unwrap()
-                    .cmp(&other_segment.parse::<usize>().unwrap());
-                match ordering {
-                    Ordering::Less => return Ordering::Less,
-                    Ordering::Equal => (),
-                    Ordering::Greater => return Ordering::Greater,
-                }
+            return Ordering::Greater;
+        }
 
-                // However, there is a special case that needs to be handled when both numbers are
-                // considered equal.
-                //
-                // To have a name for the following edge-case, let's call these "higher-level
-                // segments". Higher-level segments are string segments that aren't separated with
-                // a delimiter. E.g. on `1.10test11` the string `10test11` would be a
-                // higher-level segment that's returned as segments of:
-                //
-                // `['10', 'test', '11']`
-                //
-                // The rule is:
-                // Pure numeric higher-level segments are superior to mixed alphanumeric segments.
-                // -> `1.10` > `1.11a1`
-                // -> `1.10` > `1.11a1.2`
-                //                  ⤷ `11a1` is alphanumeric and smaller than pure numerics.
-                //
-                // The current higher-level segment is considered purely numeric if the current
-                // segment is numeric and the next segment is split via delimiter,
-                // which indicates that a new higher-level segment has started. A
-                // follow-up alphabetic segment in the same higher-level
-                // segment wouldn't have a delimiter.
-                //
-                // If there's no further segment, we reached the end of the version string, also
-                // indicating a purely numeric string.
-                let other_is_pure_numeric = other_segments
-                    .peek()
-                    .map(|seg| seg.delimiters > 0)
-                    .unwrap_or(true);
-                let self_is_pure_numeric = self_segments
-                    .peek()
-                    .map(|seg| seg.delimiters > 0)
-                    .unwrap_or(true);
-
-                // One is purely numeric, the other isn't. We can return early.
-                if self_is_pure_numeric && !other_is_pure_numeric {
-                    return Ordering::Greater;
-                } else if !self_is_pure_numeric && other_is_pure_numeric {
-                    return Ordering::Less;
-                }
+        // We finally reached the end handling special cases when the version string ended.
+        // From now on, we know that we have two actual segments that might be prefixed by
+        // some delimiters.
+
+        // Special case:
+        // If one of the segments has more leading delimiters as the other, it's considered
+        // newer.
+        // `1..0.0` > `1.2.0`
+        //         ⤷ Two delimiters, thereby always newer.
+        // `1..0.0` < `1..2.0`
+        //                ⤷ Same amount of delimiters, now `2 > 0`
+        if self_segment.delimiters != other_segment.delimiters {
+            return self_segment.delimiters.cmp(&other_segment.delimiters);
+        }
 
-                // Now we know that both are either numeric or alphanumeric and can take a look at
-                // the next segment.
-                continue;
-            }
-            // At this point, we know that the segments are alphabetic.
-            // We do a simple string comparison to determine the newer version.
-            // If the strings are equal, we check the next segments.
-            match self_segment.str_cmp(&other_segment) {
+        // Check whether any of the segments are numeric.
+        // Numeric segments are always considered newer than non-numeric segments.
+        // E.g. `1.0.0` > `1.lol.0`
+        //         ⤷ `0` vs `lol`. `0` is purely numeric and bigger than a alphanumeric one.
+        let self_is_numeric =
+            !self_segment.is_empty() && self_segment.chars().all(char::is_numeric);
+        let other_is_numeric =
+            !other_segment.is_empty() && other_segment.chars().all(char::is_numeric);
+
+        if self_is_numeric && !other_is_numeric {
+            return Ordering::Greater;
+        } else if !self_is_numeric && other_is_numeric {
+            return Ordering::Less;
+        }
+
+        // In case both are numeric, we do a number comparison.
+        // We can parse the string as we know that they only consist of digits, hence the
+        // unwrap.
+        //
+        // Trailing zeroes are to be ignored, which is automatically done by Rust's number
+        // parser. E.g. `1.0001.1` == `1.1.1`
+        //                  ⤷ `000` is ignored in comparison.
+        if self_is_numeric && other_is_numeric {
+            let ordering = self_segment
+                .parse::<usize>()
+                .unwrap()
+                .cmp(&other_segment.parse::<usize>().unwrap());
+            match ordering {
                 Ordering::Less => return Ordering::Less,
-                Ordering::Equal => continue,
+                Ordering::Equal => (),
                 Ordering::Greater => return Ordering::Greater,
             }
+
+            // However, there is a special case that needs to be handled when both numbers are
+            // considered equal.
+            //
+            // To have a name for the following edge-case, let's call these "higher-level
+            // segments". Higher-level segments are string segments that aren't separated with
+            // a delimiter. E.g. on `1.10test11` the string `10test11` would be a
+            // higher-level segment that's returned as segments of:
+            //
+            // `['10', 'test', '11']`
+            //
+            // The rule is:
+            // Pure numeric higher-level segments are superior to mixed alphanumeric segments.
+            // -> `1.10` > `1.11a1`
+            // -> `1.10` > `1.11a1.2`
+            //                  ⤷ `11a1` is alphanumeric and smaller than pure numerics.
+            //
+            // The current higher-level segment is considered purely numeric if the current
+            // segment is numeric and the next segment is split via delimiter,
+            // which indicates that a new higher-level segment has started. A
+            // follow-up alphabetic segment in the same higher-level
+            // segment wouldn't have a delimiter.
+            //
+            // If there's no further segment, we reached the end of the version string, also
+            // indicating a purely numeric string.
+            let other_is_pure_numeric = other_segments
+                .peek()
+                .map(|seg| seg.delimiters > 0)
+                .unwrap_or(true);
+            let self_is_pure_numeric = self_segments
+                .peek()
+                .map(|seg| seg.delimiters > 0)
+                .unwrap_or(true);
+
+            // One is purely numeric, the other isn't. We can return early.
+            if self_is_pure_numeric && !other_is_pure_numeric {
+                return Ordering::Greater;
+            } else if !self_is_pure_numeric && other_is_pure_numeric {
+                return Ordering::Less;
+            }
+
+            // Now we know that both are either numeric or alphanumeric and can take a look at
+            // the next segment.
+            continue;
+        }
+        // At this point, we know that the segments are alphabetic.
+        // We do a simple string comparison to determine the newer version.
+        // If the strings are equal, we check the next segments.
+        match self_segment.str_cmp(&other_segment) {
+            Ordering::Less => return Ordering::Less,
+            Ordering::Equal => continue,
+            Ordering::Greater => return Ordering::Greater,
         }
     }
 }
@@ -703,6 +967,125 @@ impl PartialEq for PackageVersion {
     }
 }
 
+impl Hash for PackageVersion {
+    /// Hashes a normalized form of the version, so that the [`Hash`]/[`Eq`] contract holds.
+    ///
+    /// As [`PackageVersion`]s are considered equal based on [`vercmp`], differently written
+    /// versions may be equal (e.g. `1.01` and `1.1`, or `1.0.` and `1.0..`).
+    /// Therefore only non-empty segments and their delimiter counts are hashed, while numeric
+    /// segments are hashed without leading zeros.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_segments(self.inner(), state);
+    }
+}
+
+/// Hashes a normalized form of a version string
+///
+/// Version strings that are equal according to [`cmp_segments`] have the same hash: Only
+/// non-empty segments and their delimiter counts are hashed, while numeric segments are hashed
+/// without leading zeros.
+fn hash_segments<H: Hasher>(version: &str, state: &mut H) {
+    for segment in VersionSegments::new(version).filter(|segment| !segment.is_empty()) {
+        segment.delimiters.hash(state);
+        if segment.chars().all(char::is_numeric) {
+            segment.segment.trim_start_matches('0').hash(state);
+        } else {
+            segment.segment.hash(state);
+        }
+    }
+}
+
+/// Compares two [`PackageVersion`]s the way pacman's `vercmp` does
+///
+/// Both versions are split into alphanumeric segments, which are compared one by one: Numeric
+/// segments are compared as integers, alphabetic segments lexically and a numeric segment is
+/// always newer than an alphabetic one. A version with an additional trailing alphabetic segment
+/// is older than one without (e.g. `1.0a` is older than `1.0`).
+///
+/// Use [`vercmp_str`] to compare full version strings (including epoch and pkgrel).
+///
+/// ## Examples
+/// ```
+/// use std::{cmp::Ordering, str::FromStr};
+///
+/// use alpm_types::{PackageVersion, vercmp};
+///
+/// # fn main() -> Result<(), alpm_types::Error> {
+/// assert_eq!(
+///     vercmp(
+///         &PackageVersion::from_str("1.0a")?,
+///         &PackageVersion::from_str("1.0")?
+///     ),
+///     Ordering::Less
+/// );
+/// assert_eq!(
+///     vercmp(
+///         &PackageVersion::from_str("1.0.0")?,
+///         &PackageVersion::from_str("1.0")?
+///     ),
+///     Ordering::Greater
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn vercmp(a: &PackageVersion, b: &PackageVersion) -> Ordering {
+    a.cmp(b)
+}
+
+/// Compares two version strings the way pacman's `vercmp` tool does
+///
+/// Contrary to [`Version::from_str`], this function accepts arbitrary strings. Like libalpm, each
+/// string is split into an epoch (defaulting to `0`), a pkgver and an optional pkgrel (the part
+/// after the last `-`).
+/// The epochs are compared first, followed by the pkgvers (see [`vercmp`]). The pkgrels are only
+/// compared if both strings provide one.
+///
+/// ## Examples
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use alpm_types::vercmp_str;
+///
+/// assert_eq!(vercmp_str("1:0", "9"), Ordering::Greater);
+/// assert_eq!(vercmp_str("1.0-2", "1.0"), Ordering::Equal);
+/// assert_eq!(vercmp_str("1.0-1", "1.0-2"), Ordering::Less);
+/// ```
+pub fn vercmp_str(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+
+    let (a_epoch, a_pkgver, a_pkgrel) = split_evr(a);
+    let (b_epoch, b_pkgver, b_pkgrel) = split_evr(b);
+
+    cmp_segments(a_epoch, b_epoch)
+        .then_with(|| cmp_segments(a_pkgver, b_pkgver))
+        .then_with(|| match (a_pkgrel, b_pkgrel) {
+            (Some(a_pkgrel), Some(b_pkgrel)) => cmp_segments(a_pkgrel, b_pkgrel),
+            _ => Ordering::Equal,
+        })
+}
+
+/// Splits a version string into epoch, pkgver and optional pkgrel
+///
+/// This mirrors `parseEVR` of libalpm: The epoch defaults to `"0"` if none (or an empty one) is
+/// provided.
+fn split_evr(version: &str) -> (&str, &str, Option<&str>) {
+    let digits = version
+        .find(|char: char| !char.is_ascii_digit())
+        .unwrap_or(version.len());
+    let (epoch, rest) = match version[digits..].strip_prefix(':') {
+        Some(rest) if digits > 0 => (&version[..digits], rest),
+        Some(rest) => ("0", rest),
+        None => ("0", version),
+    };
+
+    match rest.rsplit_once('-') {
+        Some((pkgver, pkgrel)) => (epoch, pkgver, Some(pkgrel)),
+        None => (epoch, rest, None),
+    }
+}
+
 /// The schema version of a type
 ///
 /// A `SchemaVersion` wraps a `semver::Version`, which means that the tracked version should follow [semver](https://semver.org).
@@ -740,6 +1123,44 @@ impl SchemaVersion {
     pub fn inner(&self) -> &SemverVersion {
         &self.0
     }
+
+    /// Return the major version
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::SchemaVersion;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(SchemaVersion::from_str("2.1.0")?.major(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn major(&self) -> u64 {
+        self.0.major
+    }
+
+    /// Return the minor version
+    ///
+    /// As a SchemaVersion is always backed by a semver version, the minor version is always
+    /// present. It is `0` if the SchemaVersion has been created from a single number (e.g. `"2"`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::SchemaVersion;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(SchemaVersion::from_str("2.1.0")?.minor(), Some(1));
+    /// assert_eq!(SchemaVersion::from_str("2")?.minor(), Some(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn minor(&self) -> Option<u64> {
+        Some(self.0.minor)
+    }
 }
 
 impl FromStr for SchemaVersion {
@@ -752,9 +1173,7 @@ impl FromStr for SchemaVersion {
         if !s.contains('.') {
             match s.parse() {
                 Ok(major) => Ok(SchemaVersion(SemverVersion::new(major, 0, 0))),
-                Err(e) => Err(Error::InvalidInteger {
-                    kind: e.kind().clone(),
-                }),
+                Err(e) => Err(Error::InvalidInteger { kind: *e.kind() }),
             }
         } else {
             match SemverVersion::parse(s) {
@@ -777,6 +1196,15 @@ impl Display for SchemaVersion {
 ///
 /// A `Version` tracks an optional `Epoch`, a `PackageVersion` and an optional `PackageRelease`.
 ///
+/// ## Comparison and textual fidelity
+///
+/// Comparing two `Version`s (see [`Version::vercmp`]) follows the semantics of pacman's vercmp,
+/// which normalizes the pkgver while comparing (e.g. `1.0` and `1.00` compare as equal).
+/// The textual representation is not normalized: the pkgver is kept exactly as it has been
+/// parsed, so that [`Display`] reproduces the original string (e.g. `1.0.0` is never rewritten
+/// as `1.0`). Only the [`Epoch`] is displayed in its canonical decimal representation (e.g.
+/// `01:1.0` is displayed as `1:1.0`).
+///
 /// ## Examples
 /// ```
 /// use std::str::FromStr;
@@ -789,10 +1217,16 @@ impl Display for SchemaVersion {
 /// assert_eq!(version.epoch, Some(Epoch::from_str("1")?));
 /// assert_eq!(version.pkgver, PackageVersion::new("2".to_string())?);
 /// assert_eq!(version.pkgrel, Some(PackageRelease::new("3".to_string())?));
+///
+/// // comparison normalizes, display does not
+/// let version_a = Version::from_str("1.0")?;
+/// let version_b = Version::from_str("1.00")?;
+/// assert_eq!(Version::vercmp(&version_a, &version_b), 0);
+/// assert_eq!(version_b.to_string(), "1.00");
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Deserialize, Hash, PartialEq, Eq, Serialize)]
 pub struct Version {
     /// The version of the package
     pub pkgver: PackageVersion,
@@ -816,6 +1250,43 @@ impl Version {
         }
     }
 
+    /// Create a [`VersionBuilder`] to assemble a Version from its typed components
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::{Epoch, PackageRelease, PackageVersion, Version};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let version = Version::builder()
+    ///     .epoch(Epoch::from_str("1")?)
+    ///     .pkgver(PackageVersion::new("2.0.0".to_string())?)
+    ///     .pkgrel(PackageRelease::from_str("3")?)
+    ///     .build()?;
+    /// assert_eq!(version, Version::from_str("1:2.0.0-3")?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn builder() -> VersionBuilder {
+        VersionBuilder::default()
+    }
+
+    /// Return the [`Epoch`] of the Version, if any
+    pub fn epoch(&self) -> Option<Epoch> {
+        self.epoch
+    }
+
+    /// Return the [`PackageVersion`] of the Version
+    pub fn pkgver(&self) -> &PackageVersion {
+        &self.pkgver
+    }
+
+    /// Return the [`PackageRelease`] of the Version, if any
+    pub fn pkgrel(&self) -> Option<&PackageRelease> {
+        self.pkgrel.as_ref()
+    }
+
     /// Create a new Version, which is guaranteed to have a PackageRelease
     pub fn with_pkgrel(version: &str) -> Result<Self, Error> {
         let version = Version::from_str(version)?;
@@ -828,46 +1299,422 @@ impl Version {
         }
     }
 
-    /// Compare two Versions and return a number
+    /// Create a new Version from the separate `epoch`, `pkgver` and `pkgrel` fields used in
+    /// `.SRCINFO` data
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if any of the provided components is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Version;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     Version::from_srcinfo_parts(Some("1"), "2.0.0", Some("3"))?,
+    ///     Version::from_str("1:2.0.0-3")?
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_srcinfo_parts(
+        epoch: Option<&str>,
+        pkgver: &str,
+        pkgrel: Option<&str>,
+    ) -> Result<Self, Error> {
+        Ok(Version {
+            pkgver: PackageVersion::from_str(pkgver)?,
+            epoch: epoch.map(Epoch::from_str).transpose()?,
+            pkgrel: pkgrel.map(PackageRelease::from_str).transpose()?,
+        })
+    }
+
+    /// Return the `epoch`, `pkgver` and `pkgrel` of the Version as separate strings, as used in
+    /// `.SRCINFO` data
+    ///
+    /// This is the inverse of [`Version::from_srcinfo_parts`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Version;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     Version::from_str("1:2.0.0-3")?.to_srcinfo_parts(),
+    ///     (
+    ///         Some("1".to_string()),
+    ///         "2.0.0".to_string(),
+    ///         Some("3".to_string())
+    ///     )
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn to_srcinfo_parts(&self) -> (Option<String>, String, Option<String>) {
+        (
+            self.epoch.map(|epoch| epoch.to_string()),
+            self.pkgver.to_string(),
+            self.pkgrel.as_ref().map(ToString::to_string),
+        )
+    }
+
+    /// Compare two Versions and return a number
+    ///
+    /// The comparison algorithm is based on libalpm/ pacman's vercmp behavior.
+    ///
+    /// * `1` if `a` is newer than `b`
+    /// * `0` if `a` and `b` are considered to be the same version
+    /// * `-1` if `a` is older than `b`
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Version;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    ///
+    /// assert_eq!(
+    ///     Version::vercmp(&Version::from_str("1.0.0")?, &Version::from_str("0.1.0")?),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Version::vercmp(&Version::from_str("1.0.0")?, &Version::from_str("1.0.0")?),
+    ///     0
+    /// );
+    /// assert_eq!(
+    ///     Version::vercmp(&Version::from_str("0.1.0")?, &Version::from_str("1.0.0")?),
+    ///     -1
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn vercmp(a: &Version, b: &Version) -> i8 {
+        match a.cmp(b) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1,
+        }
+    }
+
+    /// Returns `true` if the Version is older than an `installed` Version
+    ///
+    /// The comparison is done using [`Version::vercmp`] and takes all components (including the
+    /// epoch) into account. This allows to explicitly guard against a target Version not being a
+    /// downgrade.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Version;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert!(Version::from_str("1.0")?.is_downgrade_of(&Version::from_str("1.1")?));
+    /// assert!(!Version::from_str("1:1.0")?.is_downgrade_of(&Version::from_str("2.0")?));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_downgrade_of(&self, installed: &Version) -> bool {
+        self < installed
+    }
+
+    /// Compare two Versions and explain which component decides their ordering
+    ///
+    /// The ordering is the same as the one of [`Version::vercmp`].
+    /// Additionally, the first component in which the two Versions differ is returned, which
+    /// helps understanding surprising results of the comparison. For the pkgver, the index of
+    /// the first differing segment and the values of the two segments are returned.
+    ///
+    /// Note that differing components may still compare as equal (e.g. `1.01` and `1.1`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::{cmp::Ordering, str::FromStr};
+    ///
+    /// use alpm_types::{Version, VersionDifference};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let explanation = Version::from_str("1.2.0-1")?.explain_cmp(&Version::from_str("1.10-1")?);
+    /// assert_eq!(explanation.ordering, Ordering::Less);
+    /// assert_eq!(
+    ///     explanation.difference,
+    ///     Some(VersionDifference::PkgverSegment {
+    ///         index: 1,
+    ///         left: Some("2".to_string()),
+    ///         right: Some("10".to_string()),
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn explain_cmp(&self, other: &Version) -> CmpExplanation {
+        let difference = if self.epoch != other.epoch {
+            Some(VersionDifference::Epoch {
+                left: self.epoch,
+                right: other.epoch,
+            })
+        } else if let Some(difference) = self.pkgver.first_segment_difference(&other.pkgver) {
+            Some(difference)
+        } else if self.pkgrel != other.pkgrel {
+            Some(VersionDifference::Pkgrel {
+                left: self.pkgrel.clone(),
+                right: other.pkgrel.clone(),
+            })
+        } else {
+            None
+        };
+
+        CmpExplanation {
+            ordering: self.cmp(other),
+            difference,
+        }
+    }
+
+    /// Compare only the pkgver of the Version with a [`PackageVersion`]
+    ///
+    /// The epoch and pkgrel of the Version are ignored, which allows to check whether the upstream
+    /// version of a package matches a specific [`PackageVersion`].
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::{cmp::Ordering, str::FromStr};
+    ///
+    /// use alpm_types::{PackageVersion, Version};
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// let version = Version::from_str("1:1.2-3")?;
+    /// assert_eq!(
+    ///     version.pkgver_cmp(&PackageVersion::from_str("1.2")?),
+    ///     Ordering::Equal
+    /// );
+    /// assert_eq!(
+    ///     version.pkgver_cmp(&PackageVersion::from_str("1.3")?),
+    ///     Ordering::Less
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pkgver_cmp(&self, other: &PackageVersion) -> Ordering {
+        self.pkgver.cmp(other)
+    }
+
+    /// Returns `true` if a numeric segment of the pkgver is padded with leading zeros
+    ///
+    /// Leading zeros are ignored when comparing numeric segments (e.g. `1.01` is equal to `1.1`),
+    /// which may surprise maintainers. This is purely advisory and does not render the Version
+    /// invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Version;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert!(Version::from_str("1.01")?.has_ambiguous_zero_padding());
+    /// assert!(!Version::from_str("1.10")?.has_ambiguous_zero_padding());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_ambiguous_zero_padding(&self) -> bool {
+        self.pkgver.segments().any(|segment| {
+            segment.segment.len() > 1
+                && segment.segment.starts_with('0')
+                && segment.chars().all(|char| char.is_numeric())
+        })
+    }
+
+    /// Returns a new Version with an incremented pkgrel
+    ///
+    /// The pkgrel is incremented using [`PackageRelease::increment`]. A Version without pkgrel
+    /// gains a pkgrel of `1`. The epoch and pkgver are left untouched.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error::InvalidInteger`] if the pkgrel would overflow.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Version;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     Version::from_str("1:1.0.0-1")?.bump_release()?.to_string(),
+    ///     "1:1.0.0-2"
+    /// );
+    /// assert_eq!(
+    ///     Version::from_str("1.0.0-1.1")?.bump_release()?.to_string(),
+    ///     "1.0.0-1.2"
+    /// );
+    /// assert_eq!(
+    ///     Version::from_str("1.0.0")?.bump_release()?.to_string(),
+    ///     "1.0.0-1"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bump_release(&self) -> Result<Version, Error> {
+        let pkgrel = match &self.pkgrel {
+            Some(pkgrel) => pkgrel.increment()?,
+            None => PackageRelease::new("1".to_string())?,
+        };
+
+        Ok(Version {
+            pkgrel: Some(pkgrel),
+            ..self.clone()
+        })
+    }
+
+    /// Returns a new Version with an incremented epoch
+    ///
+    /// The epoch is incremented using [`Epoch::increment`]. A Version without epoch gains an
+    /// epoch of `1`. The pkgver and pkgrel are left untouched.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an [`Error::InvalidInteger`] if the epoch would overflow.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::str::FromStr;
+    ///
+    /// use alpm_types::Version;
+    ///
+    /// # fn main() -> Result<(), alpm_types::Error> {
+    /// assert_eq!(
+    ///     Version::from_str("1.0.0-1")?.bump_epoch()?.to_string(),
+    ///     "1:1.0.0-1"
+    /// );
+    /// assert_eq!(
+    ///     Version::from_str("1:1.0.0")?.bump_epoch()?.to_string(),
+    ///     "2:1.0.0"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bump_epoch(&self) -> Result<Version, Error> {
+        let epoch = match &self.epoch {
+            Some(epoch) => epoch.increment()?,
+            None => Epoch(NonZeroU64::MIN),
+        };
+
+        Ok(Version {
+            epoch: Some(epoch),
+            ..self.clone()
+        })
+    }
+}
+
+/// A builder for [`Version`]
+///
+/// Created with [`Version::builder`]. A [`PackageVersion`] is required, while [`Epoch`] and
+/// [`PackageRelease`] are optional.
+#[derive(Clone, Debug, Default)]
+pub struct VersionBuilder {
+    epoch: Option<Epoch>,
+    pkgver: Option<PackageVersion>,
+    pkgrel: Option<PackageRelease>,
+}
+
+impl VersionBuilder {
+    /// Set the [`Epoch`] of the Version
+    pub fn epoch(mut self, epoch: Epoch) -> Self {
+        self.epoch = Some(epoch);
+        self
+    }
+
+    /// Set the [`PackageVersion`] of the Version
+    pub fn pkgver(mut self, pkgver: PackageVersion) -> Self {
+        self.pkgver = Some(pkgver);
+        self
+    }
+
+    /// Set the [`PackageRelease`] of the Version
+    pub fn pkgrel(mut self, pkgrel: PackageRelease) -> Self {
+        self.pkgrel = Some(pkgrel);
+        self
+    }
+
+    /// Build the [`Version`]
     ///
-    /// The comparison algorithm is based on libalpm/ pacman's vercmp behavior.
+    /// ## Errors
     ///
-    /// * `1` if `a` is newer than `b`
-    /// * `0` if `a` and `b` are considered to be the same version
-    /// * `-1` if `a` is older than `b`
+    /// Returns an error if no [`PackageVersion`] has been set.
     ///
     /// ## Examples
     /// ```
     /// use std::str::FromStr;
     ///
-    /// use alpm_types::Version;
+    /// use alpm_types::{Epoch, Version};
     ///
     /// # fn main() -> Result<(), alpm_types::Error> {
-    ///
-    /// assert_eq!(
-    ///     Version::vercmp(&Version::from_str("1.0.0")?, &Version::from_str("0.1.0")?),
-    ///     1
-    /// );
-    /// assert_eq!(
-    ///     Version::vercmp(&Version::from_str("1.0.0")?, &Version::from_str("1.0.0")?),
-    ///     0
-    /// );
-    /// assert_eq!(
-    ///     Version::vercmp(&Version::from_str("0.1.0")?, &Version::from_str("1.0.0")?),
-    ///     -1
+    /// assert!(
+    ///     Version::builder()
+    ///         .epoch(Epoch::from_str("1")?)
+    ///         .build()
+    ///         .is_err()
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub fn vercmp(a: &Version, b: &Version) -> i8 {
-        match a.cmp(b) {
-            Ordering::Less => -1,
-            Ordering::Equal => 0,
-            Ordering::Greater => 1,
-        }
+    pub fn build(self) -> Result<Version, Error> {
+        let Some(pkgver) = self.pkgver else {
+            return Err(Error::MissingComponent {
+                component: "pkgver",
+            });
+        };
+
+        Ok(Version::new(pkgver, self.epoch, self.pkgrel))
     }
 }
 
+/// The explanation of a comparison of two [`Version`]s
+///
+/// This is returned by [`Version::explain_cmp`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CmpExplanation {
+    /// The ordering of the two Versions
+    pub ordering: Ordering,
+    /// The first component in which the two Versions differ, if any
+    pub difference: Option<VersionDifference>,
+}
+
+/// The first component in which two [`Version`]s differ
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionDifference {
+    /// The epochs of the Versions differ
+    Epoch {
+        /// The epoch of the left-hand Version
+        left: Option<Epoch>,
+        /// The epoch of the right-hand Version
+        right: Option<Epoch>,
+    },
+    /// A segment of the pkgver of the Versions differs
+    PkgverSegment {
+        /// The index of the first differing segment
+        index: usize,
+        /// The segment of the left-hand Version (if it has a segment at `index`)
+        left: Option<String>,
+        /// The segment of the right-hand Version (if it has a segment at `index`)
+        right: Option<String>,
+    },
+    /// The pkgrels of the Versions differ
+    Pkgrel {
+        /// The pkgrel of the left-hand Version
+        left: Option<PackageRelease>,
+        /// The pkgrel of the right-hand Version
+        right: Option<PackageRelease>,
+    },
+}
+
 impl FromStr for Version {
     type Err = Error;
     /// Create a new [`Version`] from a string slice.
@@ -923,8 +1770,9 @@ impl Display for Version {
     }
 }
 
-impl Ord for Version {
-    fn cmp(&self, other: &Self) -> Ordering {
+impl Version {
+    /// Compares the epoch and pkgver of two versions, ignoring their pkgrel.
+    fn cmp_epoch_and_pkgver(&self, other: &Self) -> Ordering {
         match (self.epoch, other.epoch) {
             (Some(self_epoch), Some(other_epoch)) if self_epoch.cmp(&other_epoch).is_ne() => {
                 return self_epoch.cmp(&other_epoch);
@@ -934,12 +1782,14 @@ impl Ord for Version {
             (_, _) => {}
         }
 
-        let pkgver_cmp = self.pkgver.cmp(&other.pkgver);
-        if pkgver_cmp.is_ne() {
-            return pkgver_cmp;
-        }
+        self.pkgver.cmp(&other.pkgver)
+    }
+}
 
-        self.pkgrel.cmp(&other.pkgrel)
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_epoch_and_pkgver(other)
+            .then_with(|| self.pkgrel.cmp(&other.pkgrel))
     }
 }
 
@@ -975,9 +1825,13 @@ impl PartialOrd for Version {
     strum::Display,
     strum::EnumIter,
     strum::EnumString,
+    Hash,
     PartialEq,
     Eq,
+    PartialOrd,
+    Ord,
     strum::VariantNames,
+    Deserialize,
     Serialize,
 )]
 pub enum VersionComparison {
@@ -1042,7 +1896,7 @@ impl VersionComparison {
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Deserialize, Hash, PartialEq, Eq, Serialize)]
 pub struct VersionRequirement {
     /// Version comparison function
     pub comparison: VersionComparison,
@@ -1061,6 +1915,11 @@ impl VersionRequirement {
 
     /// Returns `true` if the requirement is satisfied by the given package version.
     ///
+    /// Like in libalpm, the pkgrel of `ver` is only considered if the required version has a
+    /// pkgrel. E.g. `=1.0` is satisfied by `1.0-1` and `1.0-2`, while `=1.0-1` is only satisfied
+    /// by `1.0-1`.
+    /// The epoch is always considered.
+    ///
     /// ## Examples
     ///
     /// ```
@@ -1076,11 +1935,34 @@ impl VersionRequirement {
     /// assert!(requirement.is_satisfied_by(&Version::from_str("1.6")?));
     /// assert!(requirement.is_satisfied_by(&Version::from_str("2:1.0")?));
     /// assert!(!requirement.is_satisfied_by(&Version::from_str("1.0")?));
+    ///
+    /// let requirement = VersionRequirement::from_str("=1.5")?;
+    /// assert!(requirement.is_satisfied_by(&Version::from_str("1.5-3")?));
+    /// assert!(!requirement.is_satisfied_by(&Version::from_str("1:1.5-3")?));
     /// # Ok(())
     /// # }
     /// ```
     pub fn is_satisfied_by(&self, ver: &Version) -> bool {
-        self.comparison.is_compatible_with(ver.cmp(&self.version))
+        let ordering = match self.version.pkgrel {
+            Some(_) => ver.cmp(&self.version),
+            None => ver.cmp_epoch_and_pkgver(&self.version),
+        };
+        self.comparison.is_compatible_with(ordering)
+    }
+}
+
+impl Ord for VersionRequirement {
+    /// Orders by the target version first and by the comparison function second.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.version
+            .cmp(&other.version)
+            .then_with(|| self.comparison.cmp(&other.comparison))
+    }
+}
+
+impl PartialOrd for VersionRequirement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -1123,8 +2005,13 @@ impl FromStr for VersionRequirement {
 
 #[cfg(test)]
 mod tests {
-    use std::num::IntErrorKind;
+    use std::{
+        collections::{HashSet, hash_map::RandomState},
+        hash::BuildHasher,
+        num::IntErrorKind,
+    };
 
+    use proptest::{prop_assert_eq, proptest, test_runner::Config as ProptestConfig};
     use rstest::rstest;
 
     use super::*;
@@ -1137,6 +2024,21 @@ mod tests {
         assert_eq!(result, SchemaVersion::from_str(version))
     }
 
+    #[rstest]
+    #[case("2.1.0", 2, Some(1))]
+    #[case("2", 2, Some(0))]
+    #[case("1.2.3", 1, Some(2))]
+    fn schema_version_components(
+        #[case] version: &str,
+        #[case] major: u64,
+        #[case] minor: Option<u64>,
+    ) -> testresult::TestResult<()> {
+        let version = SchemaVersion::from_str(version)?;
+        assert_eq!(version.major(), major);
+        assert_eq!(version.minor(), minor);
+        Ok(())
+    }
+
     /// Ensure that valid buildtool version strings are parsed as expected.
     #[rstest]
     #[case(
@@ -1147,6 +2049,10 @@ mod tests {
         "1:1.0.0-1-any",
         BuildToolVersion::new(Version::from_str("1:1.0.0-1").unwrap(), Some(Architecture::from_str("any").unwrap())),
     )]
+    #[case(
+        "1.2.3-1-x86_64",
+        BuildToolVersion::new(Version::from_str("1.2.3-1").unwrap(), Some(Architecture::X86_64)),
+    )]
     #[case(
         "1.0.0",
         BuildToolVersion::new(Version::from_str("1.0.0").unwrap(), None),
@@ -1154,9 +2060,10 @@ mod tests {
     fn valid_buildtoolver_new(#[case] buildtoolver: &str, #[case] expected: BuildToolVersion) {
         assert_eq!(
             BuildToolVersion::from_str(buildtoolver),
-            Ok(expected),
+            Ok(expected.clone()),
             "Expected valid parse of buildtoolver '{buildtoolver}'"
         );
+        assert_eq!(expected.to_string(), buildtoolver);
     }
 
     /// Ensure that invalid buildtool version strings produce the respective errors.
@@ -1171,6 +2078,7 @@ mod tests {
         }
     )]
     #[case("1.0.0-1-foo", strum::ParseError::VariantNotFound.into())]
+    #[case("1.2.3-1", strum::ParseError::VariantNotFound.into())]
     fn invalid_buildtoolver_new(#[case] buildtoolver: &str, #[case] expected: Error) {
         assert_eq!(
             BuildToolVersion::from_str(buildtoolver),
@@ -1308,14 +2216,86 @@ mod tests {
     }
 
     #[rstest]
-    #[case("1", Ok(Epoch(NonZeroUsize::new(1).unwrap())))]
+    #[case("1", Ok(Epoch(NonZeroU64::new(1).unwrap())))]
+    #[case("18446744073709551615", Ok(Epoch(NonZeroU64::MAX)))]
+    #[case("18446744073709551616", Err(Error::InvalidInteger { kind: IntErrorKind::PosOverflow }))]
+    #[case("99999999999999999999", Err(Error::InvalidInteger { kind: IntErrorKind::PosOverflow }))]
     #[case("0", Err(Error::InvalidInteger { kind: IntErrorKind::Zero }))]
     #[case("-0", Err(Error::InvalidInteger { kind: IntErrorKind::InvalidDigit }))]
     #[case("z", Err(Error::InvalidInteger { kind: IntErrorKind::InvalidDigit }))]
+    #[case("01", Ok(Epoch(NonZeroU64::new(1).unwrap())))]
+    #[case("+1", Ok(Epoch(NonZeroU64::new(1).unwrap())))]
     fn epoch(#[case] version: &str, #[case] result: Result<Epoch, Error>) {
         assert_eq!(result, Epoch::from_str(version));
     }
 
+    #[rstest]
+    #[case("1.0.0")]
+    #[case("1.0.0-1")]
+    #[case("1:1.0.0")]
+    #[case("2:1.0.0-1.1")]
+    fn version_srcinfo_parts_roundtrip(#[case] version: &str) -> testresult::TestResult<()> {
+        let version = Version::from_str(version)?;
+        let (epoch, pkgver, pkgrel) = version.to_srcinfo_parts();
+        assert_eq!(
+            Version::from_srcinfo_parts(epoch.as_deref(), &pkgver, pkgrel.as_deref())?,
+            version
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case(Some("0"), "1.0.0", None, Error::InvalidInteger { kind: IntErrorKind::Zero })]
+    #[case(None, "1.0.0", Some("a"), Error::RegexDoesNotMatch {
+        value: "a".to_string(),
+        regex_type: "pkgrel".to_string(),
+        regex: PKGREL_REGEX.to_string(),
+    })]
+    fn version_from_invalid_srcinfo_parts(
+        #[case] epoch: Option<&str>,
+        #[case] pkgver: &str,
+        #[case] pkgrel: Option<&str>,
+        #[case] error: Error,
+    ) {
+        assert_eq!(
+            Version::from_srcinfo_parts(epoch, pkgver, pkgrel),
+            Err(error)
+        );
+    }
+
+    #[rstest]
+    #[case("1.2.3", BumpLevel::Major, "2.0.0")]
+    #[case("1.2.3", BumpLevel::Minor, "1.3.0")]
+    #[case("1.2.3", BumpLevel::Patch, "1.2.4")]
+    #[case("1.2.3.4", BumpLevel::Minor, "1.3.0.0")]
+    #[case("1.2.3rc1", BumpLevel::Minor, "1.3.0")]
+    #[case("1", BumpLevel::Patch, "1.0.1")]
+    #[case("9.99", BumpLevel::Minor, "9.100")]
+    fn pkgver_bump(
+        #[case] version: &str,
+        #[case] level: BumpLevel,
+        #[case] expected: &str,
+    ) -> testresult::TestResult<()> {
+        let version = PackageVersion::from_str(version)?;
+        assert_eq!(version.bump(level)?.to_string(), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1.2.3rc1", BumpLevel::Patch, IntErrorKind::InvalidDigit)]
+    #[case("foo", BumpLevel::Major, IntErrorKind::InvalidDigit)]
+    #[case("1..3", BumpLevel::Minor, IntErrorKind::Empty)]
+    #[case("18446744073709551615", BumpLevel::Major, IntErrorKind::PosOverflow)]
+    fn pkgver_bump_invalid(
+        #[case] version: &str,
+        #[case] level: BumpLevel,
+        #[case] kind: IntErrorKind,
+    ) -> testresult::TestResult<()> {
+        let version = PackageVersion::from_str(version)?;
+        assert_eq!(version.bump(level), Err(Error::InvalidInteger { kind }));
+        Ok(())
+    }
+
     /// Make sure that we can parse valid **pkgver** strings.
     #[rstest]
     #[case("foo")]
@@ -1417,6 +2397,299 @@ mod tests {
         assert_eq!(format!("{}", version), to_str);
     }
 
+    #[rstest]
+    #[case("1.01", "1.1", Ordering::Equal, Some(VersionDifference::PkgverSegment {
+        index: 1,
+        left: Some("01".to_string()),
+        right: Some("1".to_string()),
+    }))]
+    #[case("1.0", "1.0.1", Ordering::Less, Some(VersionDifference::PkgverSegment {
+        index: 2,
+        left: None,
+        right: Some("1".to_string()),
+    }))]
+    #[case("2:1.0", "1:2.0", Ordering::Greater, Some(VersionDifference::Epoch {
+        left: Some(Epoch::from_str("2").unwrap()),
+        right: Some(Epoch::from_str("1").unwrap()),
+    }))]
+    #[case("1.0-1", "1.0-2", Ordering::Less, Some(VersionDifference::Pkgrel {
+        left: Some(PackageRelease::from_str("1").unwrap()),
+        right: Some(PackageRelease::from_str("2").unwrap()),
+    }))]
+    #[case("1:1.0-1", "1:1.0-1", Ordering::Equal, None)]
+    fn version_explain_cmp(
+        #[case] left: &str,
+        #[case] right: &str,
+        #[case] ordering: Ordering,
+        #[case] difference: Option<VersionDifference>,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            Version::from_str(left)?.explain_cmp(&Version::from_str(right)?),
+            CmpExplanation {
+                ordering,
+                difference
+            }
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1.0", "1.1", true)]
+    #[case("1.0-1", "1.0-2", true)]
+    #[case("1.1", "1:1.0", true)]
+    #[case("1:1.0", "2.0", false)]
+    #[case("1.1", "1.0", false)]
+    #[case("1.0-1", "1.0-1", false)]
+    fn version_is_downgrade_of(
+        #[case] version: &str,
+        #[case] installed: &str,
+        #[case] downgrade: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            Version::from_str(version)?.is_downgrade_of(&Version::from_str(installed)?),
+            downgrade
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1:1.2-3", "1.2", Ordering::Equal)]
+    #[case("1.2-3", "1.2.0", Ordering::Less)]
+    #[case("1.2.1", "1.2", Ordering::Greater)]
+    #[case("2:1.0", "1.00", Ordering::Equal)]
+    fn version_pkgver_cmp(
+        #[case] version: &str,
+        #[case] pkgver: &str,
+        #[case] expected: Ordering,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            Version::from_str(version)?.pkgver_cmp(&PackageVersion::from_str(pkgver)?),
+            expected
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1.01", true)]
+    #[case("1.00", true)]
+    #[case("1:007-1", true)]
+    #[case("1.0a01", true)]
+    #[case("1.1", false)]
+    #[case("1.10", false)]
+    #[case("1.0.0", false)]
+    #[case("1.0-01", false)]
+    fn version_has_ambiguous_zero_padding(
+        #[case] version: &str,
+        #[case] ambiguous: bool,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            Version::from_str(version)?.has_ambiguous_zero_padding(),
+            ambiguous
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1:2.0.0-3", Some("1"), "2.0.0", Some("3"))]
+    #[case("1:2.0.0", Some("1"), "2.0.0", None)]
+    #[case("2.0.0-3", None, "2.0.0", Some("3"))]
+    #[case("2.0.0", None, "2.0.0", None)]
+    fn version_builder(
+        #[case] expected: &str,
+        #[case] epoch: Option<&str>,
+        #[case] pkgver: &str,
+        #[case] pkgrel: Option<&str>,
+    ) -> testresult::TestResult<()> {
+        let mut builder = Version::builder().pkgver(PackageVersion::from_str(pkgver)?);
+        if let Some(epoch) = epoch {
+            builder = builder.epoch(Epoch::from_str(epoch)?);
+        }
+        if let Some(pkgrel) = pkgrel {
+            builder = builder.pkgrel(PackageRelease::from_str(pkgrel)?);
+        }
+        let version = builder.build()?;
+
+        assert_eq!(version.to_string(), expected);
+        assert_eq!(version, Version::from_str(expected)?);
+        assert_eq!(version.epoch(), epoch.map(Epoch::from_str).transpose()?);
+        assert_eq!(version.pkgver().to_string(), pkgver);
+        assert_eq!(version.pkgrel().map(ToString::to_string).as_deref(), pkgrel);
+        Ok(())
+    }
+
+    #[test]
+    fn version_builder_missing_pkgver() {
+        assert_eq!(
+            Version::builder()
+                .epoch(Epoch::from_str("1").unwrap())
+                .pkgrel(PackageRelease::from_str("1").unwrap())
+                .build(),
+            Err(Error::MissingComponent {
+                component: "pkgver"
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("1", Ok("2"))]
+    #[case("9", Ok("10"))]
+    #[case("1.1", Ok("1.2"))]
+    #[case("2.9", Ok("2.10"))]
+    #[case(
+        "18446744073709551615",
+        Err(Error::InvalidInteger { kind: IntErrorKind::PosOverflow })
+    )]
+    fn pkgrel_increment(#[case] pkgrel: &str, #[case] expected: Result<&str, Error>) {
+        assert_eq!(
+            PackageRelease::from_str(pkgrel)
+                .unwrap()
+                .increment()
+                .map(|pkgrel| pkgrel.to_string()),
+            expected.map(str::to_string)
+        );
+    }
+
+    #[rstest]
+    #[case("1", Ok(Epoch(NonZeroU64::new(2).unwrap())))]
+    #[case(
+        "18446744073709551615",
+        Err(Error::InvalidInteger { kind: IntErrorKind::PosOverflow })
+    )]
+    fn epoch_increment(#[case] epoch: &str, #[case] expected: Result<Epoch, Error>) {
+        assert_eq!(Epoch::from_str(epoch).unwrap().increment(), expected);
+    }
+
+    #[rstest]
+    #[case("1.0-1", "1.0-2", "1:1.0-1")]
+    #[case("1.0-1.1", "1.0-1.2", "1:1.0-1.1")]
+    #[case("1.0", "1.0-1", "1:1.0")]
+    #[case("2:1.0-1", "2:1.0-2", "3:1.0-1")]
+    fn version_bump(
+        #[case] version: &str,
+        #[case] bumped_release: &str,
+        #[case] bumped_epoch: &str,
+    ) -> testresult::TestResult<()> {
+        let version = Version::from_str(version)?;
+        assert_eq!(version.bump_release()?.to_string(), bumped_release);
+        assert_eq!(version.bump_epoch()?.to_string(), bumped_epoch);
+        Ok(())
+    }
+
+    /// Ensure that pkgrels, which are written differently but compare equal, are equal.
+    #[test]
+    fn version_pkgrel_eq() -> testresult::TestResult<()> {
+        let a = Version::from_str("1.0-1")?;
+        let b = Version::from_str("1.0-01")?;
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert_eq!(a, b);
+        let hasher = RandomState::new();
+        assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+        assert_eq!(
+            PackageRelease::from_str("2")?.cmp(&PackageRelease::from_str("10")?),
+            Ordering::Less
+        );
+        Ok(())
+    }
+
+    /// Ensure that versions which compare equal, but are written differently, hash equally.
+    #[rstest]
+    #[case(&["1.1", "1.01", "01.1", "1.001"], 1)]
+    #[case(&["1.0.", "1.0..", "1.0...."], 1)]
+    #[case(&["1.0", "1+0", "1_0"], 1)]
+    #[case(&["1.0", "1.0.0", "1.0a", "1..0"], 4)]
+    #[case(&["1:1.01-1", "1:1.1-1", "1.1-1", "1:1.1"], 3)]
+    #[case(&["1.0-1", "1.0-01", "1.0-001"], 1)]
+    #[case(&["1.0-1.1", "1.0-1.01", "1.0-1.10"], 2)]
+    fn version_hash_set(
+        #[case] versions: &[&str],
+        #[case] len: usize,
+    ) -> testresult::TestResult<()> {
+        let set = versions
+            .iter()
+            .map(|version| Version::from_str(version))
+            .collect::<Result<HashSet<_>, _>>()?;
+        assert_eq!(set.len(), len);
+        Ok(())
+    }
+
+    /// Ensure that equal versions have the same normalized form, that is equal and hashes equally.
+    #[rstest]
+    #[case(&["1.1", "1.01", "01.1", "1.001"], "1.1")]
+    #[case(&["1.0.", "1.0..", "1.0....", "1.0+"], "1.0.")]
+    #[case(&["1.0", "1+0", "1_0", "1.00"], "1.0")]
+    #[case(&["1..0", "1+_0", "1._00"], "1..0")]
+    #[case(&["1.0a", "1.00a"], "1.0a")]
+    #[case(&["1.0.0", "1_0+0", "001.000.0"], "1.0.0")]
+    #[case(&["2.3rc1", "2.03rc01"], "2.3rc1")]
+    #[case(&["20250101"], "20250101")]
+    fn package_version_normalized(
+        #[case] versions: &[&str],
+        #[case] normalized: &str,
+    ) -> testresult::TestResult<()> {
+        let hash = |version: &PackageVersion| {
+            let mut hasher = std::hash::DefaultHasher::new();
+            version.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let expected = PackageVersion::from_str(normalized)?;
+        for version in versions {
+            let version = PackageVersion::from_str(version)?;
+            let result = version.normalized();
+            assert_eq!(result.to_string(), normalized);
+            assert_eq!(result, version);
+            assert_eq!(hash(&result), hash(&version));
+            assert_eq!(hash(&version), hash(&expected));
+            assert_eq!(result.normalized().to_string(), normalized);
+        }
+        Ok(())
+    }
+
+    /// Ensure that a version is displayed exactly as it has been parsed.
+    #[rstest]
+    #[case("1.0.0.0")]
+    #[case("1.00")]
+    #[case("01.0")]
+    #[case("1.0.0")]
+    #[case("1.0.0-1")]
+    #[case("1.0.0-1.1")]
+    #[case("1:1.0.0")]
+    #[case("1:1.00-1.1")]
+    #[case("18446744073709551615:1-0")]
+    #[case("1.0+r10.g1234abc")]
+    #[case("1.0_rc1+2")]
+    #[case("r1234.abcdef0")]
+    #[case("1..0")]
+    #[case("1.0.")]
+    #[case("2:a")]
+    fn version_display_roundtrip(#[case] version: &str) -> testresult::TestResult<()> {
+        assert_eq!(Version::from_str(version)?.to_string(), version);
+        Ok(())
+    }
+
+    /// Ensure that the epoch is displayed in its canonical representation.
+    #[rstest]
+    #[case("01:1.0", "1:1.0")]
+    #[case("+1:1.0-1", "1:1.0-1")]
+    fn version_display_canonical_epoch(
+        #[case] version: &str,
+        #[case] expected: &str,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(Version::from_str(version)?.to_string(), expected);
+        Ok(())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn version_display_roundtrip_any(
+            version in r"([1-9][0-9]{0,18}:)?[[:alnum:]][[:alnum:]_+.]{0,30}(-[0-9]{1,5}(\.[0-9]{1,5})?)?"
+        ) {
+            prop_assert_eq!(Version::from_str(&version).unwrap().to_string(), version);
+        }
+    }
+
     #[rstest]
     #[case(Version::from_str("1"), Version::from_str("1"), Ordering::Equal)]
     #[case(Version::from_str("2"), Version::from_str("1"), Ordering::Greater)]
@@ -1504,6 +2777,44 @@ mod tests {
         Version::from_str("1.a1a.1"),
         Ordering::Equal
     )]
+    // epoch dominates pkgver and pkgrel
+    #[case(
+        Version::from_str("1:1.0.0"),
+        Version::from_str("1.0.0"),
+        Ordering::Greater
+    )]
+    #[case(
+        Version::from_str("1:1.0.0"),
+        Version::from_str("2.0.0"),
+        Ordering::Greater
+    )]
+    #[case(
+        Version::from_str("1:1.0.0-1"),
+        Version::from_str("9.0.0-9"),
+        Ordering::Greater
+    )]
+    #[case(Version::from_str("1.0.0"), Version::from_str("1:0.1"), Ordering::Less)]
+    #[case(
+        Version::from_str("2:0.1"),
+        Version::from_str("1:9.9"),
+        Ordering::Greater
+    )]
+    #[case(
+        Version::from_str("1:1.0.0"),
+        Version::from_str("1:1.0.0"),
+        Ordering::Equal
+    )]
+    // pkgrels are compared segment-wise
+    #[case(
+        Version::from_str("1.0.0-2"),
+        Version::from_str("1.0.0-10"),
+        Ordering::Less
+    )]
+    #[case(
+        Version::from_str("1.0.0-1.10"),
+        Version::from_str("1.0.0-1.9"),
+        Ordering::Greater
+    )]
     fn version_cmp(
         #[case] version_a: Result<Version, Error>,
         #[case] version_b: Result<Version, Error>,
@@ -1529,6 +2840,161 @@ mod tests {
         assert_eq!(Version::vercmp(&version_a, &version_b), vercmp_result);
     }
 
+    #[rstest]
+    #[case("1.0a", "1.0", Ordering::Less)]
+    #[case("1.0.0", "1.0", Ordering::Greater)]
+    #[case("1.0", "1.0", Ordering::Equal)]
+    #[case("1.0alpha", "1.0b", Ordering::Less)]
+    fn package_version_vercmp(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] expected: Ordering,
+    ) -> testresult::TestResult<()> {
+        assert_eq!(
+            vercmp(&PackageVersion::from_str(a)?, &PackageVersion::from_str(b)?),
+            expected
+        );
+        Ok(())
+    }
+
+    /// Ensure that [`vercmp_str`] behaves like libalpm (see `test/util/vercmptest.sh` of pacman).
+    #[rstest]
+    // all similar length, no pkgrel
+    #[case("1.5.0", "1.5.0", Ordering::Equal)]
+    #[case("1.5.1", "1.5.0", Ordering::Greater)]
+    // mixed length
+    #[case("1.5.1", "1.5", Ordering::Greater)]
+    // with pkgrel, simple
+    #[case("1.5.0-1", "1.5.0-1", Ordering::Equal)]
+    #[case("1.5.0-1", "1.5.0-2", Ordering::Less)]
+    #[case("1.5.0-1", "1.5.1-1", Ordering::Less)]
+    #[case("1.5.0-2", "1.5.1-1", Ordering::Less)]
+    // with pkgrel, mixed lengths
+    #[case("1.5-1", "1.5.1-1", Ordering::Less)]
+    #[case("1.5-2", "1.5.1-1", Ordering::Less)]
+    #[case("1.5-2", "1.5.1-2", Ordering::Less)]
+    // mixed pkgrel inclusion
+    #[case("1.5", "1.5-1", Ordering::Equal)]
+    #[case("1.5-1", "1.5", Ordering::Equal)]
+    #[case("1.1-1", "1.1", Ordering::Equal)]
+    #[case("1.0-1", "1.1", Ordering::Less)]
+    #[case("1.1-1", "1.0", Ordering::Greater)]
+    // alphanumeric versions
+    #[case("1.5b-1", "1.5-1", Ordering::Less)]
+    #[case("1.5b", "1.5", Ordering::Less)]
+    #[case("1.5b-1", "1.5", Ordering::Less)]
+    #[case("1.5b", "1.5.1", Ordering::Less)]
+    // from the manpage
+    #[case("1.0a", "1.0alpha", Ordering::Less)]
+    #[case("1.0alpha", "1.0b", Ordering::Less)]
+    #[case("1.0b", "1.0beta", Ordering::Less)]
+    #[case("1.0beta", "1.0rc", Ordering::Less)]
+    #[case("1.0rc", "1.0", Ordering::Less)]
+    // alpha-dotted versions
+    #[case("1.5.a", "1.5", Ordering::Greater)]
+    #[case("1.5.b", "1.5.a", Ordering::Greater)]
+    #[case("1.5.1", "1.5.b", Ordering::Greater)]
+    // alpha dots and dashes
+    #[case("1.5.b-1", "1.5.b", Ordering::Equal)]
+    #[case("1.5-1", "1.5.b", Ordering::Less)]
+    // same/similar content, differing separators
+    #[case("2.0", "2_0", Ordering::Equal)]
+    #[case("2.0_a", "2_0.a", Ordering::Equal)]
+    #[case("2.0a", "2.0.a", Ordering::Less)]
+    #[case("2___a", "2_a", Ordering::Greater)]
+    // epoch included version comparisons
+    #[case("0:1.0", "0:1.0", Ordering::Equal)]
+    #[case("0:1.0", "0:1.1", Ordering::Less)]
+    #[case("1:1.0", "0:1.0", Ordering::Greater)]
+    #[case("1:1.0", "0:1.1", Ordering::Greater)]
+    #[case("1:1.0", "2:1.1", Ordering::Less)]
+    // epoch + sometimes present pkgrel
+    #[case("1:1.0", "0:1.0-1", Ordering::Greater)]
+    #[case("1:1.0-1", "0:1.1-1", Ordering::Greater)]
+    // epoch included on one version
+    #[case("0:1.0", "1.0", Ordering::Equal)]
+    #[case("0:1.1", "1.0", Ordering::Greater)]
+    #[case("0:1.1", "1.1", Ordering::Equal)]
+    #[case("1:1.0", "1.0", Ordering::Greater)]
+    #[case("1:1.1", "1.1", Ordering::Greater)]
+    #[case("1:1.1", "1.11", Ordering::Greater)]
+    #[case("1:0", "9", Ordering::Greater)]
+    fn libalpm_vercmp_str(#[case] a: &str, #[case] b: &str, #[case] expected: Ordering) {
+        assert_eq!(vercmp_str(a, b), expected, "Comparing {a} and {b}");
+        assert_eq!(
+            vercmp_str(b, a),
+            expected.reverse(),
+            "Comparing {b} and {a}"
+        );
+    }
+
+    /// Ensure that an explicit zero epoch is rejected by [`Version`], as an [`Epoch`] is
+    /// guaranteed to be greater than zero, while [`vercmp_str`] treats it like an absent epoch.
+    #[rstest]
+    #[case("0:1.0.0", "1.0.0")]
+    #[case("0:1.0.0-1", "1.0.0-1")]
+    fn version_explicit_zero_epoch(
+        #[case] explicit: &str,
+        #[case] implicit: &str,
+    ) -> testresult::TestResult<()> {
+        assert!(Version::from_str(explicit).is_err());
+        assert_eq!(vercmp_str(explicit, implicit), Ordering::Equal);
+        assert_eq!(
+            vercmp_str(explicit, &format!("1:{implicit}")),
+            Ordering::Less
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("1")]
+    #[case("1.0.0-1")]
+    #[case("2:1.0.0-1.1")]
+    #[case("1.0+r10.g1234abc")]
+    fn version_serde_roundtrip(#[case] version: &str) -> testresult::TestResult<()> {
+        let version = Version::from_str(version)?;
+        let json = serde_json::to_string(&version)?;
+        assert_eq!(serde_json::from_str::<Version>(&json)?, version);
+
+        let json = serde_json::to_string(&version.pkgver)?;
+        assert_eq!(
+            serde_json::from_str::<PackageVersion>(&json)?,
+            version.pkgver
+        );
+        let json = serde_json::to_string(&version.epoch)?;
+        assert_eq!(serde_json::from_str::<Option<Epoch>>(&json)?, version.epoch);
+        let json = serde_json::to_string(&version.pkgrel)?;
+        assert_eq!(
+            serde_json::from_str::<Option<PackageRelease>>(&json)?,
+            version.pkgrel
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("\"1-1\"")]
+    #[case("0")]
+    fn version_components_deserialize_invalid(#[case] json: &str) {
+        assert!(serde_json::from_str::<PackageVersion>(json).is_err());
+        assert!(serde_json::from_str::<PackageRelease>(json).is_err());
+        assert!(serde_json::from_str::<Epoch>(json).is_err());
+    }
+
+    #[rstest]
+    #[case(">=1.0")]
+    #[case("<1:2.0-1")]
+    fn version_requirement_serde_roundtrip(
+        #[case] requirement: &str,
+    ) -> testresult::TestResult<()> {
+        let requirement = VersionRequirement::from_str(requirement)?;
+        let json = serde_json::to_string(&requirement)?;
+        assert_eq!(
+            serde_json::from_str::<VersionRequirement>(&json)?,
+            requirement
+        );
+        Ok(())
+    }
+
     /// Ensure that valid version comparison strings can be parsed.
     #[rstest]
     #[case("<", VersionComparison::Less)]
@@ -1570,6 +3036,10 @@ mod tests {
         comparison: VersionComparison::Greater,
         version: Version::from_str("3.1").unwrap(),
     })]
+    #[case("=1.2.3-1", VersionRequirement {
+        comparison: VersionComparison::Equal,
+        version: Version::from_str("1.2.3-1").unwrap(),
+    })]
     fn valid_version_requirement(#[case] requirement: &str, #[case] expected: VersionRequirement) {
         assert_eq!(
             requirement.parse(),
@@ -1584,6 +3054,25 @@ mod tests {
     #[case("<>3.1", strum::ParseError::VariantNotFound.into())]
     #[case("3.1", strum::ParseError::VariantNotFound.into())]
     #[case("=>3.1", strum::ParseError::VariantNotFound.into())]
+    #[case("=", Error::MissingComponent { component: "operator" })]
+    // whitespace around the operator is rejected
+    #[case(" >=3.1", strum::ParseError::VariantNotFound.into())]
+    #[case(
+        ">= 3.1",
+        Error::RegexDoesNotMatch {
+            value: " 3.1".to_string(),
+            regex_type: "pkgver".to_string(),
+            regex: PKGVER_REGEX.to_string()
+        }
+    )]
+    #[case(
+        ">=3.1 ",
+        Error::RegexDoesNotMatch {
+            value: "3.1 ".to_string(),
+            regex_type: "pkgver".to_string(),
+            regex: PKGVER_REGEX.to_string()
+        }
+    )]
     #[case(
         "<3.1>3.2",
         Error::RegexDoesNotMatch {
@@ -1600,11 +3089,21 @@ mod tests {
         );
     }
 
-    /// Check whether a version requirement (>= 1.0) is fulfilled by a given version string.
+    /// Check whether a version requirement is fulfilled by a given version string.
+    ///
+    /// The pkgrel of the version is ignored, if the requirement does not specify one.
     #[rstest]
     #[case("=1", "1", true)]
     #[case("=1", "1.0", false)]
-    #[case("=1", "1-1", false)]
+    #[case("=1", "1-1", true)]
+    #[case("=1.2.3", "1.2.3-5", true)]
+    #[case("=1.2.3-2", "1.2.3-5", false)]
+    #[case("=1.2.3-5", "1.2.3-5", true)]
+    #[case(">=1.2.3", "1.2.3-1", true)]
+    #[case(">1.2.3", "1.2.3-1", false)]
+    #[case("<1.2.3", "1.2.3-1", false)]
+    #[case("=1.2.3", "1:1.2.3-1", false)]
+    #[case("=1:1.2.3", "1.2.3-1", false)]
     #[case("=1", "1:1", false)]
     #[case("=1", "0.9", false)]
     #[case("<42", "41", true)]